@@ -0,0 +1,70 @@
+// src/behaviors/mod.rs
+pub mod random;
+pub mod utility;
+
+use std::fmt;
+use rand::RngCore;
+use crate::models::estajo::{Estajo, LifeStage};
+use crate::simulation::relationships::Relationship;
+
+/// Ce qu'un estajo décide de faire pendant un tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Intent {
+    Eat,
+    Help(u32),
+    Hurt(u32),
+    Plot(u32),
+    Mate(u32),
+    /// Poursuivre son ambition (défi, complot ou accaparement)
+    Scheme,
+    Rest,
+}
+
+impl fmt::Display for Intent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Intent::Eat => write!(f, "eat"),
+            Intent::Help(id) => write!(f, "help Estajo_{}", id),
+            Intent::Hurt(id) => write!(f, "hurt Estajo_{}", id),
+            Intent::Plot(id) => write!(f, "plot against Estajo_{}", id),
+            Intent::Mate(id) => write!(f, "mate with Estajo_{}", id),
+            Intent::Scheme => write!(f, "scheme"),
+            Intent::Rest => write!(f, "rest"),
+        }
+    }
+}
+
+/// Ce qu'un estajo perçoit autour de lui au moment de décider.
+pub struct Surroundings<'a> {
+    pub estajo: &'a Estajo,
+    pub stage: LifeStage,
+    /// Nourriture disponible à portée d'interaction
+    pub food: f32,
+    /// Son ambition dépasse le seuil de passage à l'acte
+    pub ambitious: bool,
+    /// Nombre d'estajoj vivants dans le monde
+    pub population: usize,
+    pub neighbours: Vec<Neighbour<'a>>,
+}
+
+pub struct Neighbour<'a> {
+    pub estajo: &'a Estajo,
+    /// Ce que l'estajo qui décide pense de ce voisin
+    pub relationship: Relationship,
+    /// Attrait comme partenaire, `None` si l'accouplement est impossible
+    pub appeal: Option<f64>,
+    /// Membre du même groupe
+    pub ally: bool,
+    /// Membre d'un autre groupe
+    pub rival: bool,
+}
+
+/// Façon dont un estajo choisit son action à partir de ce qu'il perçoit.
+/// Le monde appelle `decide` une fois par tick pour chaque estajo vivant et
+/// exécute l'intention retournée si elle est encore possible.
+pub trait Behavior {
+    /// Nom sous lequel le comportement est enregistré dans le monde
+    fn name(&self) -> &str;
+
+    fn decide(&mut self, view: &Surroundings, rng: &mut dyn RngCore) -> Intent;
+}
@@ -0,0 +1,130 @@
+// src/behaviors/random.rs
+use rand::{Rng, RngCore};
+use rand::distributions::{Distribution, WeightedIndex};
+use crate::models::event::Action;
+use crate::simulation::parameters::SimulationParams;
+use crate::simulation::relationships;
+use super::{Behavior, Intent, Surroundings};
+
+/// Comportement historique, à coups de pile ou face : les chances globales
+/// d'interaction, d'accouplement et d'ambition sont réparties entre tous les
+/// estajoj, les affamés cherchent à manger avec `hunger_chance`.
+pub struct RandomBehavior {
+    pub interaction_chance: f32,
+    pub reproduction_chance: f32,
+    pub hunger_chance: f32,
+    pub ambition_chance: f32,
+    pub hunger_threshold: f32,
+    pub bias: f32,
+}
+
+impl RandomBehavior {
+    pub const NAME: &'static str = "random";
+
+    pub fn new(params: &SimulationParams) -> Self {
+        Self {
+            interaction_chance: params.random.interaction_chance,
+            reproduction_chance: params.random.reproduction_chance,
+            hunger_chance: params.random.hunger_chance,
+            ambition_chance: params.random.ambition_chance,
+            hunger_threshold: params.food.hunger_threshold,
+            bias: params.relationships.bias,
+        }
+    }
+}
+
+impl Behavior for RandomBehavior {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn decide(&mut self, view: &Surroundings, rng: &mut dyn RngCore) -> Intent {
+        let share = view.population.max(1) as f32;
+        if view.estajo.needs.hunger < self.hunger_threshold && rng.gen::<f32>() < self.hunger_chance {
+            return Intent::Eat;
+        }
+
+        // Cible et action sont tirées ensemble : on aide ceux qu'on apprécie,
+        // on blesse ceux qu'on rancune, on complote contre ceux dont on se méfie
+        if rng.gen::<f32>() < self.interaction_chance / share {
+            let mut choices = Vec::new();
+            let mut weights = Vec::new();
+            for n in &view.neighbours {
+                let rel = &n.relationship;
+                for (action, feeling) in [
+                    (Action::Help, rel.affinity),
+                    (Action::Hurt, rel.grudge),
+                    (Action::Plot, 50.0 - rel.trust),
+                ] {
+                    choices.push((n.estajo.id, action));
+                    weights.push(relationships::weight(feeling, self.bias));
+                }
+            }
+            if let Ok(dist) = WeightedIndex::new(&weights) {
+                let (target, action) = &choices[dist.sample(rng)];
+                return match action {
+                    Action::Help => Intent::Help(*target),
+                    Action::Hurt => Intent::Hurt(*target),
+                    Action::Plot => Intent::Plot(*target),
+                };
+            }
+        }
+
+        if rng.gen::<f32>() < self.reproduction_chance / share {
+            let partners: Vec<(u32, f64)> = view.neighbours.iter()
+                .filter_map(|n| Some((n.estajo.id, n.appeal?)))
+                .collect();
+            if let Ok(dist) = WeightedIndex::new(partners.iter().map(|(_, appeal)| *appeal)) {
+                return Intent::Mate(partners[dist.sample(rng)].0);
+            }
+        }
+
+        if view.ambitious && rng.gen::<f32>() < self.ambition_chance / share {
+            return Intent::Scheme;
+        }
+        Intent::Rest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::behaviors::Neighbour;
+    use crate::models::estajo::LifeStage;
+    use crate::simulation::relationships::Relationship;
+    use crate::test_support::pair;
+
+    #[test]
+    fn test_coin_flips() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (mut estajo, other) = pair(&mut rng);
+        estajo.needs.hunger = 10.0;
+        let view = Surroundings {
+            estajo: &estajo,
+            stage: LifeStage::Adult,
+            food: 10.0,
+            ambitious: false,
+            population: 1,
+            neighbours: vec![Neighbour {
+                estajo: &other,
+                relationship: Relationship::default(),
+                appeal: None,
+                ally: false,
+                rival: false,
+            }],
+        };
+
+        let mut behavior = RandomBehavior::new(&SimulationParams::default());
+        behavior.hunger_chance = 1.0;
+        assert_eq!(behavior.decide(&view, &mut rng), Intent::Eat);
+
+        behavior.hunger_chance = 0.0;
+        behavior.interaction_chance = 1.0;
+        assert!(matches!(behavior.decide(&view, &mut rng), Intent::Help(1) | Intent::Hurt(1) | Intent::Plot(1)));
+
+        behavior.interaction_chance = 0.0;
+        assert_eq!(behavior.decide(&view, &mut rng), Intent::Rest);
+    }
+}
@@ -0,0 +1,246 @@
+// src/behaviors/utility.rs
+use rand::{Rng, RngCore};
+use rand::distributions::{Distribution, WeightedIndex};
+use serde::{Deserialize, Serialize};
+use crate::models::estajo::LifeStage;
+use crate::models::traits::hooks;
+use crate::simulation::parameters::UtilityParams;
+use super::{Behavior, Intent, Surroundings};
+
+/// Courbe de réponse : transforme une entrée normalisée (0..1) en score (0..1).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Curve {
+    Linear { slope: f32, offset: f32 },
+    /// x^exponent : au-delà de 1, seules les valeurs extrêmes comptent
+    Power { exponent: f32 },
+    /// Sigmoïde centrée sur `midpoint`
+    Logistic { midpoint: f32, steepness: f32 },
+    Constant { value: f32 },
+}
+
+impl Curve {
+    pub fn evaluate(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        let y = match *self {
+            Curve::Linear { slope, offset } => slope * x + offset,
+            Curve::Power { exponent } => x.powf(exponent),
+            Curve::Logistic { midpoint, steepness } => 1.0 / (1.0 + (-steepness * (x - midpoint)).exp()),
+            Curve::Constant { value } => value,
+        };
+        y.clamp(0.0, 1.0)
+    }
+}
+
+/// Score de chaque option envisageable, les cibles étant les voisins qui s'y
+/// prêtent le mieux.
+pub fn score(view: &Surroundings, params: &UtilityParams, rng: &mut impl Rng) -> Vec<(Intent, f32)> {
+    let estajo = view.estajo;
+    let needs = &estajo.needs;
+    let factor = |hook| estajo.genetics.factor(hook);
+    let mut options = vec![
+        (Intent::Rest, params.rest.evaluate(1.0 - estajo.stats.energy / 100.0)),
+    ];
+
+    if view.food > 0.0 {
+        let hunger = 1.0 - needs.hunger / 100.0;
+        options.push((Intent::Eat, params.eat.evaluate(hunger) * factor(hooks::FOOD_COMPETITION)));
+    }
+    if view.ambitious && view.stage != LifeStage::Child {
+        options.push((Intent::Scheme, params.scheme.evaluate(needs.ambition / 100.0)));
+    }
+
+    // On aide qui l'on apprécie ou qui en a besoin, surtout dans son groupe ;
+    // on blesse qui l'on rancune, on complote contre qui l'on se méfie,
+    // d'autant plus volontiers qu'il appartient à un autre groupe
+    let best = |input: &dyn Fn(usize) -> f32| {
+        (0..view.neighbours.len())
+            .map(|i| (i, input(i)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    };
+    let neighbours = &view.neighbours;
+    let ally = |i: usize| if neighbours[i].ally { params.ally_bonus } else { 0.0 };
+    let rival = |i: usize| if neighbours[i].rival { params.rival_hostility } else { 0.0 };
+    if let Some((i, x)) = best(&|i| {
+        let n = &neighbours[i];
+        (n.relationship.affinity.max(0.0) / 100.0 + 1.0 - n.estajo.life / 100.0) / 2.0 + ally(i)
+    }) {
+        options.push((Intent::Help(neighbours[i].estajo.id), params.help.evaluate(x) * factor(hooks::HELP_HEAL)));
+    }
+    if let Some((i, x)) = best(&|i| neighbours[i].relationship.grudge / 100.0 + rival(i)) {
+        options.push((Intent::Hurt(neighbours[i].estajo.id), params.hurt.evaluate(x) * factor(hooks::HURT_DAMAGE)));
+    }
+    if let Some((i, x)) = best(&|i| (50.0 - neighbours[i].relationship.trust) / 50.0 + rival(i)) {
+        options.push((Intent::Plot(neighbours[i].estajo.id), params.plot.evaluate(x) * factor(hooks::PLOT_SUCCESS)));
+    }
+
+    // Le partenaire est tiré selon son attrait
+    let partners: Vec<(u32, f64)> = neighbours.iter()
+        .filter_map(|n| Some((n.estajo.id, n.appeal?)))
+        .collect();
+    if let Ok(dist) = WeightedIndex::new(partners.iter().map(|(_, appeal)| *appeal)) {
+        let partner = partners[dist.sample(rng)].0;
+        let drive = needs.reproduction / 100.0;
+        options.push((Intent::Mate(partner), params.mate.evaluate(drive) * factor(hooks::FERTILITY)));
+    }
+
+    options
+}
+
+/// Choisit une option au prorata de son score élevé à la puissance
+/// `sharpness` : 0 = au hasard, plus c'est grand plus le meilleur l'emporte.
+pub fn decide(view: &Surroundings, params: &UtilityParams, rng: &mut impl Rng) -> Intent {
+    let options = score(view, params, rng);
+    let weights = options.iter().map(|(_, s)| (*s as f64).powf(params.sharpness as f64));
+    match WeightedIndex::new(weights) {
+        Ok(dist) => options[dist.sample(rng)].0,
+        Err(_) => Intent::Rest,
+    }
+}
+
+/// Comportement par défaut : chaque option est tirée selon son score.
+pub struct UtilityBehavior {
+    pub params: UtilityParams,
+}
+
+impl UtilityBehavior {
+    pub const NAME: &'static str = "utility";
+
+    pub fn new(params: UtilityParams) -> Self {
+        Self { params }
+    }
+}
+
+impl Behavior for UtilityBehavior {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn decide(&mut self, view: &Surroundings, mut rng: &mut dyn RngCore) -> Intent {
+        decide(view, &self.params, &mut rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::behaviors::Neighbour;
+    use crate::simulation::relationships::Relationship;
+    use crate::models::estajo::Estajo;
+    use crate::test_support::{estajo, pair};
+
+    fn view<'a>(estajo: &'a Estajo, others: &'a [Estajo], relationship: Relationship) -> Surroundings<'a> {
+        Surroundings {
+            estajo,
+            stage: LifeStage::Adult,
+            food: 100.0,
+            ambitious: false,
+            population: others.len() + 1,
+            neighbours: others.iter()
+                .map(|e| Neighbour {
+                    estajo: e,
+                    relationship: relationship.clone(),
+                    appeal: None,
+                    ally: false,
+                    rival: false,
+                })
+                .collect(),
+        }
+    }
+
+    fn scored(options: &[(Intent, f32)], wanted: fn(&Intent) -> bool) -> f32 {
+        options.iter().find(|(i, _)| wanted(i)).map(|(_, s)| *s).unwrap_or(0.0)
+    }
+
+    #[test]
+    fn test_curves() {
+        assert_eq!(Curve::Linear { slope: 0.5, offset: 0.2 }.evaluate(1.0), 0.7);
+        assert_eq!(Curve::Power { exponent: 2.0 }.evaluate(0.5), 0.25);
+        assert_eq!(Curve::Logistic { midpoint: 0.5, steepness: 10.0 }.evaluate(0.5), 0.5);
+        assert_eq!(Curve::Constant { value: 3.0 }.evaluate(0.0), 1.0);
+        assert_eq!(Curve::Linear { slope: 1.0, offset: 0.0 }.evaluate(2.0), 1.0);
+    }
+
+    #[test]
+    fn test_hunger_drives_eating() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut estajo = estajo(0, &mut rng);
+        let params = UtilityParams::default();
+
+        estajo.needs.hunger = 90.0;
+        let fed = score(&view(&estajo, &[], Relationship::default()), &params, &mut rng);
+        estajo.needs.hunger = 5.0;
+        let starving = score(&view(&estajo, &[], Relationship::default()), &params, &mut rng);
+        let eat = |i: &Intent| *i == Intent::Eat;
+        assert!(scored(&starving, eat) > scored(&fed, eat));
+        assert!(scored(&starving, eat) > scored(&starving, |i| *i == Intent::Rest));
+    }
+
+    #[test]
+    fn test_relationships_pick_targets() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let (estajo, other) = pair(&mut rng);
+        let others = [other];
+        let params = UtilityParams::default();
+        let hated = Relationship { affinity: -50.0, trust: 10.0, grudge: 90.0 };
+        let liked = Relationship { affinity: 90.0, trust: 90.0, grudge: 0.0 };
+
+        let towards_enemy = score(&view(&estajo, &others, hated), &params, &mut rng);
+        let towards_friend = score(&view(&estajo, &others, liked), &params, &mut rng);
+        let hurt = |i: &Intent| matches!(i, Intent::Hurt(1));
+        let help = |i: &Intent| matches!(i, Intent::Help(1));
+        assert!(scored(&towards_enemy, hurt) > scored(&towards_friend, hurt));
+        assert!(scored(&towards_friend, help) > scored(&towards_enemy, help));
+    }
+
+    #[test]
+    fn test_groups_bias_help_and_hostility() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let (estajo, other) = pair(&mut rng);
+        let others = [other];
+        let params = UtilityParams::default();
+        let neutral = score(&view(&estajo, &others, Relationship::default()), &params, &mut rng);
+
+        let mut allied = view(&estajo, &others, Relationship::default());
+        allied.neighbours[0].ally = true;
+        let allied = score(&allied, &params, &mut rng);
+        let mut rivals = view(&estajo, &others, Relationship::default());
+        rivals.neighbours[0].rival = true;
+        let rivals = score(&rivals, &params, &mut rng);
+
+        let help = |i: &Intent| matches!(i, Intent::Help(1));
+        let hurt = |i: &Intent| matches!(i, Intent::Hurt(1));
+        assert!(scored(&allied, help) > scored(&neutral, help));
+        assert!(scored(&rivals, hurt) > scored(&neutral, hurt));
+    }
+
+    #[test]
+    fn test_mating_needs_a_partner() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let (mut estajo, other) = pair(&mut rng);
+        estajo.needs.reproduction = 90.0;
+        let others = [other];
+        let params = UtilityParams::default();
+
+        let mut surroundings = view(&estajo, &others, Relationship::default());
+        let mate = |i: &Intent| matches!(i, Intent::Mate(1));
+        assert_eq!(scored(&score(&surroundings, &params, &mut rng), mate), 0.0);
+        surroundings.neighbours[0].appeal = Some(1.0);
+        assert!(scored(&score(&surroundings, &params, &mut rng), mate) > 0.0);
+    }
+
+    #[test]
+    fn test_sharpness_favours_best_option() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let mut estajo = estajo(0, &mut rng);
+        estajo.needs.hunger = 5.0;
+        let params = UtilityParams { sharpness: 10.0, ..Default::default() };
+        let surroundings = view(&estajo, &[], Relationship::default());
+        let eats = (0..100)
+            .filter(|_| decide(&surroundings, &params, &mut rng) == Intent::Eat)
+            .count();
+        assert!(eats > 90);
+    }
+}
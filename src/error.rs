@@ -0,0 +1,41 @@
+// src/error.rs
+use std::fmt;
+use std::io;
+
+/// Erreurs de la simulation.
+#[derive(Debug)]
+pub enum Error {
+    /// Le monde n'a plus d'estajo vivant et ne peut plus avancer
+    Extinction,
+    /// L'historique n'a pas pu être écrit
+    Storage(io::Error),
+    /// Paramètres incohérents, avec la raison
+    InvalidParameters(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Extinction => write!(f, "all estajoj are dead"),
+            Error::Storage(e) => write!(f, "history storage failed: {}", e),
+            Error::InvalidParameters(reason) => write!(f, "invalid parameters: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Storage(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Storage(e)
+    }
+}
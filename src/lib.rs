@@ -1,5 +1,9 @@
+pub mod error;
 pub mod models;
-//pub mod behaviors;
+pub mod behaviors;
 pub mod simulation;
 pub mod storage;
-pub mod ui;
\ No newline at end of file
+pub mod ui;
+
+#[cfg(test)]
+pub(crate) mod test_support;
@@ -36,12 +36,8 @@ fn main() -> Result<(), Box<dyn Error>> {
            break;
        }
        
-       if let Err(e) = app.tick() {
-           if e.to_string() == "All estajoj are dead!" {
-               break;
-           }
-           return Err(e);
-       }
+       // Une fois une condition d'arrêt remplie, le monde reste affiché jusqu'à `q`
+       app.tick()?;
    }
 
    // Restore terminal
@@ -49,5 +45,9 @@ fn main() -> Result<(), Box<dyn Error>> {
    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
    terminal.show_cursor()?;
 
+   if let Some(end) = app.world.end() {
+       println!("Simulation ended at tick {}: {}", app.world.current_tick(), end);
+   }
+
    Ok(())
 }
\ No newline at end of file
@@ -1,7 +1,12 @@
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use rand::prelude::*;
-use super::event::{Event, EventType, Need, StateChange};
-use super::Needs;
+use super::event::{DeathCause, Event, EventType, Need, StateChange};
+use super::{Needs, Position};
+use super::stats::Stats;
+pub use super::genetics::Genetics;
+use super::traits::hooks;
+use crate::simulation::parameters::GeneticsParams;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub enum Sex {
@@ -9,31 +14,37 @@ pub enum Sex {
    Female
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
-pub struct Genetics {
-   pub energy_factor: f32,
-   pub satisfaction_factor: f32,
-   pub influence_factor: f32,
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub enum LifeStage {
+   Child,
+   Adult,
+   Elder,
 }
 
-impl Genetics {
-   pub fn new() -> Self {
-       let mut rng = thread_rng();
-       Self {
-           energy_factor: rng.gen_range(0.8..1.2),
-           satisfaction_factor: rng.gen_range(0.8..1.2),
-           influence_factor: rng.gen_range(0.8..1.2),
+impl std::fmt::Display for LifeStage {
+   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+       match self {
+           LifeStage::Child => write!(f, "Child"),
+           LifeStage::Adult => write!(f, "Adult"),
+           LifeStage::Elder => write!(f, "Elder"),
        }
    }
+}
 
-   pub fn mix_with(&self, other: &Genetics) -> Genetics {
-       let mut rng = thread_rng();
-       Self {
-           energy_factor: (self.energy_factor + other.energy_factor) / 2.0 * rng.gen_range(0.9..1.1),
-           satisfaction_factor: (self.satisfaction_factor + other.satisfaction_factor) / 2.0 * rng.gen_range(0.9..1.1),
-           influence_factor: (self.influence_factor + other.influence_factor) / 2.0 * rng.gen_range(0.9..1.1),
-       }
-   }
+/// Grossesse en cours : l'enfant est conçu dès l'accouplement et naît à terme.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Pregnancy {
+   pub father: u32,
+   /// Ticks écoulés depuis la conception
+   pub elapsed: u32,
+   pub embryo: Box<Estajo>,
+}
+
+/// Maladie contractée, depuis `elapsed` ticks.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Infection {
+   pub disease: String,
+   pub elapsed: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -42,22 +53,59 @@ pub struct Estajo {
    pub name: String,
    pub sex: Sex,
    pub life: f32,
+   /// Âge en ticks
+   #[serde(default)]
+   pub age: u32,
+   #[serde(default)]
+   pub parents: Vec<u32>,
+   #[serde(default)]
+   pub generation: u32,
+   #[serde(default)]
+   pub position: Position,
+   #[serde(default)]
+   pub pregnancy: Option<Pregnancy>,
+   /// Ticks restants avant de pouvoir concevoir à nouveau
+   #[serde(default)]
+   pub birth_cooldown: u32,
    pub needs: Needs,
+   /// Nourriture mise de côté, consommée avant d'aller chercher
+   #[serde(default)]
+   pub hoard: f32,
+   #[serde(default)]
+   pub stats: Stats,
+   #[serde(default)]
+   pub infection: Option<Infection>,
+   /// Ticks d'immunité restants, par maladie
+   #[serde(default)]
+   pub immunity: BTreeMap<String, u32>,
    pub genetics: Genetics,
    pub history: Vec<Event>,
+   #[serde(default)]
+   pub death_cause: Option<DeathCause>,
 }
 
 impl Estajo {
-   pub fn new(id: u32, name: String) -> Self {
-       let sex = if thread_rng().gen_bool(0.5) { Sex::Male } else { Sex::Female };
+   pub fn new(id: u32, name: String, rng: &mut impl Rng) -> Self {
+       let sex = if rng.gen_bool(0.5) { Sex::Male } else { Sex::Female };
        Self {
            id,
            name,
            sex,
            life: 100.0,
-           needs: Needs::new(),
-           genetics: Genetics::new(),
+           age: 0,
+           parents: Vec::new(),
+           generation: 0,
+           position: Position::default(),
+           pregnancy: None,
+           birth_cooldown: 0,
+           needs: Needs::new(rng),
+           hoard: 0.0,
+           stats: Stats::default(),
+           infection: None,
+           immunity: BTreeMap::new(),
+           genetics: Genetics::new(&GeneticsParams::default(), rng),
            history: Vec::new(),
+           death_cause: None,
        }
    }
 
@@ -65,34 +113,78 @@ impl Estajo {
         self.life > 0.0
     }
 
-    pub fn take_damage(&mut self, amount: f32) {
+    pub fn take_damage(&mut self, amount: f32, cause: DeathCause) {
+        if !self.is_alive() {
+            return;
+        }
         self.life = (self.life - amount).max(0.0);
         if !self.is_alive() {
+            self.death_cause = Some(cause);
             self.add_event(Event::new(
-                EventType::StateChange(StateChange::Death),
-                "Has died".to_string()
+                EventType::StateChange(StateChange::Death(cause)),
+                format!("Has died of {}", cause)
             ));
         }
     }
 
-   pub fn reproduce_with(&self, partner: &Estajo) -> Option<Estajo> {
+    /// Perd de la vie proportionnellement au manque de nourriture sous
+    /// `threshold` ; un facteur d'énergie élevé rend plus résistant.
+    pub fn starve(&mut self, threshold: f32, max_damage: f32) -> f32 {
+        if threshold <= 0.0 || self.needs.hunger >= threshold {
+            return 0.0;
+        }
+        let deficit = 1.0 - self.needs.hunger / threshold;
+        let amount = max_damage * deficit / self.genetics.divisor(hooks::STARVATION_RESISTANCE);
+        self.take_damage(amount, DeathCause::Starvation);
+        amount
+    }
+
+    pub fn heal(&mut self, amount: f32) {
+        if self.is_alive() {
+            self.life = (self.life + amount).min(100.0);
+        }
+    }
+
+    pub fn can_conceive(&self) -> bool {
+        self.sex == Sex::Female && self.pregnancy.is_none() && self.birth_cooldown == 0
+    }
+
+   pub fn reproduce_with(
+       &self,
+       partner: &Estajo,
+       id: u32,
+       genetics: &GeneticsParams,
+       inbreeding_depression: f32,
+       rng: &mut impl Rng,
+   ) -> Option<Estajo> {
        if self.sex == partner.sex { 
            return None; 
        }
        
        Some(Estajo {
-           id: thread_rng().gen(),
-           name: format!("Child_{}_{}", self.id, partner.id),
-           sex: if thread_rng().gen_bool(0.5) { Sex::Male } else { Sex::Female },
+           id,
+           name: format!("Estajo_{}", id),
+           sex: if rng.gen_bool(0.5) { Sex::Male } else { Sex::Female },
            life: 100.0,
-           needs: Needs::new(),
-           genetics: self.genetics.mix_with(&partner.genetics),
+           age: 0,
+           parents: vec![self.id, partner.id],
+           generation: self.generation.max(partner.generation) + 1,
+           position: self.position,
+           pregnancy: None,
+           birth_cooldown: 0,
+           needs: Needs::new(rng),
+           hoard: 0.0,
+           stats: Stats::default(),
+           infection: None,
+           immunity: BTreeMap::new(),
+           genetics: self.genetics.mix_with(&partner.genetics, genetics, inbreeding_depression, rng),
            history: Vec::new(),
+           death_cause: None,
        })
    }
 
-   pub fn update_needs(&mut self) {
-       self.needs.update();
+   pub fn update_needs(&mut self, hunger_drain: f32, rng: &mut impl Rng) {
+       self.needs.update(hunger_drain, rng);
        if self.needs.hunger < 20.0 {
            self.add_event(Event::new(
                EventType::Need(Need::Food),
@@ -105,6 +197,12 @@ impl Estajo {
                "Ambitious".to_string()
            ));
        }
+       if self.needs.reproduction > 80.0 {
+           self.add_event(Event::new(
+               EventType::Need(Need::Reproduction),
+               "Wants to mate".to_string()
+           ));
+       }
    }
 
    pub fn add_event(&mut self, event: Event) {
@@ -115,46 +213,79 @@ impl Estajo {
 #[cfg(test)]
 mod tests {
    use super::*;
+   use rand::rngs::StdRng;
 
    #[test]
    fn test_create_estajo() {
-       let estajo = Estajo::new(1, "Test".to_string());
+       let mut rng = StdRng::seed_from_u64(42);
+       let estajo = Estajo::new(1, "Test".to_string(), &mut rng);
        assert_eq!(estajo.id, 1);
        assert_eq!(estajo.history.len(), 0);
    }
 
    #[test]
    fn test_reproduction() {
-       let mut e1 = Estajo::new(1, "Parent1".to_string());
-       let mut e2 = Estajo::new(2, "Parent2".to_string());
+       let mut rng = StdRng::seed_from_u64(42);
+       let mut e1 = Estajo::new(1, "Parent1".to_string(), &mut rng);
+       let mut e2 = Estajo::new(2, "Parent2".to_string(), &mut rng);
        
        e1.sex = Sex::Male;
        e2.sex = Sex::Female;
 
-       let child = e1.reproduce_with(&e2);
+       let child = e1.reproduce_with(&e2, 3, &GeneticsParams::default(), 0.0, &mut rng);
        assert!(child.is_some());
        
        let child = child.unwrap();
-       assert!(child.name.contains("Child"));
+       assert_eq!(child.id, 3);
+       assert_eq!(child.name, "Estajo_3");
+       assert_eq!(child.parents, vec![1, 2]);
+       assert_eq!(child.generation, 1);
    }
 
    #[test]
    fn test_same_sex_reproduction() {
-       let mut e1 = Estajo::new(1, "Parent1".to_string());
-       let mut e2 = Estajo::new(2, "Parent2".to_string());
+       let mut rng = StdRng::seed_from_u64(42);
+       let mut e1 = Estajo::new(1, "Parent1".to_string(), &mut rng);
+       let mut e2 = Estajo::new(2, "Parent2".to_string(), &mut rng);
        
        e1.sex = Sex::Male;
        e2.sex = Sex::Male;
 
-       let child = e1.reproduce_with(&e2);
+       let child = e1.reproduce_with(&e2, 3, &GeneticsParams::default(), 0.0, &mut rng);
        assert!(child.is_none());
    }
 
+   #[test]
+   fn test_starvation() {
+       let mut rng = StdRng::seed_from_u64(42);
+       let mut estajo = Estajo::new(1, "Test".to_string(), &mut rng);
+       assert_eq!(estajo.starve(10.0, 5.0), 0.0);
+
+       estajo.needs.hunger = 0.0;
+       estajo.life = 1.0;
+       assert!(estajo.starve(10.0, 5.0) > 0.0);
+       assert!(!estajo.is_alive());
+       assert_eq!(estajo.death_cause, Some(DeathCause::Starvation));
+       assert!(matches!(
+           estajo.history.last().unwrap().event_type,
+           EventType::StateChange(StateChange::Death(DeathCause::Starvation))
+       ));
+
+       // Sans résistance du tout, les dégâts restent finis
+       let mut frail = Estajo::new(2, "Frail".to_string(), &mut rng);
+       frail.genetics.set(crate::models::traits::ENERGY, 0.0);
+       frail.needs.hunger = 5.0;
+       let damage = frail.starve(10.0, 0.1);
+       assert!(damage.is_finite());
+       assert!(frail.is_alive());
+   }
+
    #[test]
    fn test_needs_update() {
-       let mut estajo = Estajo::new(1, "Test".to_string());
+       let mut rng = StdRng::seed_from_u64(42);
+       let mut estajo = Estajo::new(1, "Test".to_string(), &mut rng);
        let initial_hunger = estajo.needs.hunger;
-       estajo.update_needs();
+       estajo.update_needs(2.0, &mut rng);
        assert!(estajo.needs.hunger < initial_hunger);
    }
 }
\ No newline at end of file
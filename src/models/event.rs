@@ -1,10 +1,15 @@
-use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self};
+use super::estajo::LifeStage;
+use crate::simulation::catastrophe::CatastropheKind;
+use crate::simulation::environment::Season;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct Event {
-   pub timestamp: DateTime<Local>,
+   /// Tick de la simulation pendant lequel l'événement est survenu,
+   /// renseigné par le monde à la fin du tick (0 avant le premier tick)
+   #[serde(default)]
+   pub tick: u32,
    pub event_type: EventType,
    pub details: String,
 }
@@ -14,6 +19,9 @@ pub enum EventType {
    Action(Action),
    StateChange(StateChange),
    Need(Need),
+   Group(GroupChange),
+   Season(Season),
+   Catastrophe(CatastropheKind),
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -35,14 +43,38 @@ pub enum StateChange {
     EnergyUpdate,
     SatisfactionUpdate,
     InfluenceUpdate,
+    Conception,
     Reproduction,
-    Death,
+    Miscarriage,
+    LifeStage(LifeStage),
+    Death(DeathCause),
+    Leadership,
+    Infection,
+    Recovery,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub enum GroupChange {
+    Formation,
+    Dissolution,
+    Split,
+    Merge,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub enum DeathCause {
+    Aging,
+    Starvation,
+    Injury,
+    Disease,
+    Drowning,
+    Predation,
 }
 
 impl Event {
    pub fn new(event_type: EventType, details: String) -> Self {
        Self {
-           timestamp: Local::now(),
+           tick: 0,
            event_type,
            details,
        }
@@ -51,11 +83,14 @@ impl Event {
 
 impl fmt::Display for Event {
    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-       let time = self.timestamp.format("%H:%M:%S");
+       let tick = format!("[{:>5}]", self.tick);
        match &self.event_type {
-           EventType::Action(action) => write!(f, "{} {} {}", time, action, self.details),
-           EventType::StateChange(change) => write!(f, "{} {} {}", time, change, self.details),
-           EventType::Need(need) => write!(f, "{} {} {}", time, need, self.details),
+           EventType::Action(action) => write!(f, "{} {} {}", tick, action, self.details),
+           EventType::StateChange(change) => write!(f, "{} {} {}", tick, change, self.details),
+           EventType::Need(need) => write!(f, "{} {} {}", tick, need, self.details),
+           EventType::Group(change) => write!(f, "{} {} {}", tick, change, self.details),
+           EventType::Season(season) => write!(f, "{} {} {}", tick, season.to_string().to_uppercase(), self.details),
+           EventType::Catastrophe(kind) => write!(f, "{} {} {}", tick, kind.to_string().to_uppercase(), self.details),
        }
    }
 }
@@ -76,8 +111,38 @@ impl fmt::Display for StateChange {
            StateChange::EnergyUpdate => write!(f, "ENERGY"),
            StateChange::SatisfactionUpdate => write!(f, "SATISFACTION"),
            StateChange::InfluenceUpdate => write!(f, "INFLUENCE"),
+           StateChange::Conception => write!(f, "CONCEPTION"),
            StateChange::Reproduction => write!(f, "REPRODUCTION"),
-           StateChange::Death => write!(f, "DEAD"),
+           StateChange::Miscarriage => write!(f, "MISCARRIAGE"),
+           StateChange::LifeStage(stage) => write!(f, "{}", stage.to_string().to_uppercase()),
+           StateChange::Death(cause) => write!(f, "DEAD ({})", cause),
+           StateChange::Leadership => write!(f, "LEADERSHIP"),
+           StateChange::Infection => write!(f, "INFECTED"),
+           StateChange::Recovery => write!(f, "RECOVERED"),
+       }
+   }
+}
+
+impl fmt::Display for GroupChange {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+       match self {
+           GroupChange::Formation => write!(f, "GROUP FORMED"),
+           GroupChange::Dissolution => write!(f, "GROUP DISSOLVED"),
+           GroupChange::Split => write!(f, "GROUP SPLIT"),
+           GroupChange::Merge => write!(f, "GROUP MERGED"),
+       }
+   }
+}
+
+impl fmt::Display for DeathCause {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+       match self {
+           DeathCause::Aging => write!(f, "aging"),
+           DeathCause::Starvation => write!(f, "starvation"),
+           DeathCause::Injury => write!(f, "injury"),
+           DeathCause::Disease => write!(f, "disease"),
+           DeathCause::Drowning => write!(f, "drowning"),
+           DeathCause::Predation => write!(f, "predation"),
        }
    }
 }
@@ -0,0 +1,303 @@
+// src/models/genetics.rs
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use crate::simulation::parameters::GeneticsParams;
+use super::traits::{hooks, Inheritance, TraitDef};
+
+/// Plus petit facteur par lequel on divise : un trait configuré à 0 ne doit
+/// donner ni dégâts infinis ni chances indéfinies.
+pub const MIN_DIVISOR: f32 = 0.01;
+
+/// Un gène porté en deux exemplaires, l'un hérité de chaque parent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Locus {
+   pub name: String,
+   pub alleles: [f32; 2],
+   /// Points d'accroche modulés par ce gène, repris du registre
+   #[serde(default)]
+   pub consumers: Vec<String>,
+}
+
+impl Locus {
+   fn random(def: &TraitDef, rng: &mut impl Rng) -> Self {
+       Self {
+           name: def.name.clone(),
+           alleles: [
+               def.distribution.sample(rng).clamp(def.min, def.max),
+               def.distribution.sample(rng).clamp(def.min, def.max),
+           ],
+           consumers: def.consumers.clone(),
+       }
+   }
+
+   pub fn express(&self, inheritance: &Inheritance) -> f32 {
+       let high = self.alleles[0].max(self.alleles[1]);
+       let low = self.alleles[0].min(self.alleles[1]);
+       let dominance = match inheritance {
+           Inheritance::Mendelian { dominance } => *dominance,
+           Inheritance::Blend | Inheritance::Random => 0.5,
+       };
+       high * dominance + low * (1.0 - dominance)
+   }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Genetics {
+   /// Loci dans l'ordre du registre, qui détermine leur liaison
+   pub genome: Vec<Locus>,
+   /// Phénotype exprimé, par nom de trait
+   pub traits: BTreeMap<String, f32>,
+}
+
+impl Genetics {
+   pub fn new(params: &GeneticsParams, rng: &mut impl Rng) -> Self {
+       let genome = params.traits.iter().map(|def| Locus::random(def, rng)).collect();
+       Self::express(genome, params, 1.0)
+   }
+
+   fn express(genome: Vec<Locus>, params: &GeneticsParams, fitness: f32) -> Self {
+       let traits = genome.iter()
+           .map(|locus| {
+               let value = match params.traits.get(&locus.name) {
+                   Some(def) => locus.express(&def.inheritance),
+                   None => locus.express(&Inheritance::Blend),
+               };
+               (locus.name.clone(), value * fitness)
+           })
+           .collect();
+       Self { genome, traits }
+   }
+
+   /// Valeur exprimée d'un trait, s'il est porté.
+   pub fn value(&self, name: &str) -> Option<f32> {
+       self.traits.get(name).copied()
+   }
+
+   /// Force la valeur exprimée d'un trait.
+   pub fn set(&mut self, name: &str, value: f32) {
+       self.traits.insert(name.to_string(), value);
+   }
+
+   /// Aptitude génétique perçue lors du choix d'un partenaire : moyenne des
+   /// traits qui consomment `hooks::FITNESS`, 1 si aucun.
+   pub fn fitness(&self) -> f32 {
+       let values: Vec<f32> = self.genome.iter()
+           .filter(|locus| locus.consumers.iter().any(|c| c == hooks::FITNESS))
+           .filter_map(|locus| self.value(&locus.name))
+           .collect();
+       if values.is_empty() {
+           return 1.0;
+       }
+       values.iter().sum::<f32>() / values.len() as f32
+   }
+
+   /// Multiplicateur appliqué à un point d'accroche : produit des traits
+   /// qui le consomment, 1 si aucun.
+   pub fn factor(&self, hook: &str) -> f32 {
+       self.genome.iter()
+           .filter(|locus| locus.consumers.iter().any(|c| c == hook))
+           .filter_map(|locus| self.value(&locus.name))
+           .product()
+   }
+
+   /// Facteur d'un point d'accroche qui divise (résistances), borné à
+   /// `MIN_DIVISOR`.
+   pub fn divisor(&self, hook: &str) -> f32 {
+       self.factor(hook).max(MIN_DIVISOR)
+   }
+
+   /// Produit un gamète : un allèle par locus, en changeant de brin avec
+   /// une probabilité `recombination_rate` entre deux loci voisins, puis
+   /// en appliquant les mutations.
+   fn gamete(&self, params: &GeneticsParams, rng: &mut impl Rng) -> BTreeMap<String, f32> {
+       let mut strand = rng.gen_range(0..2);
+       let mut gamete = BTreeMap::new();
+       for (i, locus) in self.genome.iter().enumerate() {
+           if i > 0 && rng.gen::<f32>() < params.recombination_rate {
+               strand = 1 - strand;
+           }
+           let Some(def) = params.traits.get(&locus.name) else { continue };
+           let mut allele = match def.inheritance {
+               Inheritance::Mendelian { .. } => locus.alleles[strand],
+               Inheritance::Blend => (locus.alleles[0] + locus.alleles[1]) / 2.0,
+               Inheritance::Random => def.distribution.sample(rng),
+           };
+           if def.inheritance != Inheritance::Random && rng.gen::<f32>() < params.mutation_rate {
+               allele += rng.gen_range(-1.0..=1.0) * params.mutation_magnitude;
+           }
+           gamete.insert(locus.name.clone(), allele.clamp(def.min, def.max));
+       }
+       gamete
+   }
+
+   /// `depression` (0..1) réduit tous les traits exprimés de l'enfant,
+   /// pour pénaliser la consanguinité.
+   pub fn mix_with(
+       &self,
+       other: &Genetics,
+       params: &GeneticsParams,
+       depression: f32,
+       rng: &mut impl Rng,
+   ) -> Genetics {
+       let from_self = self.gamete(params, rng);
+       let from_other = other.gamete(params, rng);
+       let genome = params.traits.iter()
+           .map(|def| {
+               // Un trait ajouté au registre après la naissance des parents est tiré à neuf
+               let mut locus = Locus::random(def, rng);
+               match def.inheritance {
+                   Inheritance::Random => {}
+                   Inheritance::Blend => {
+                       if let (Some(a), Some(b)) = (from_self.get(&def.name), from_other.get(&def.name)) {
+                           locus.alleles = [(a + b) / 2.0; 2];
+                       }
+                   }
+                   Inheritance::Mendelian { .. } => {
+                       if let Some(a) = from_self.get(&def.name) {
+                           locus.alleles[0] = *a;
+                       }
+                       if let Some(b) = from_other.get(&def.name) {
+                           locus.alleles[1] = *b;
+                       }
+                   }
+               }
+               locus
+           })
+           .collect();
+       Self::express(genome, params, 1.0 - depression.clamp(0.0, 1.0))
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+   use rand::SeedableRng;
+   use rand::rngs::StdRng;
+   use crate::models::traits::{TraitDistribution, TraitRegistry, ENERGY};
+
+   fn stable() -> GeneticsParams {
+       GeneticsParams {
+           mutation_rate: 0.0,
+           ..Default::default()
+       }
+   }
+
+   #[test]
+   fn test_same_seed_same_genetics() {
+       let params = GeneticsParams::default();
+       let a = Genetics::new(&params, &mut StdRng::seed_from_u64(7));
+       let b = Genetics::new(&params, &mut StdRng::seed_from_u64(7));
+       assert_eq!(a, b);
+   }
+
+   #[test]
+   fn test_dominance() {
+       let locus = Locus { name: ENERGY.to_string(), alleles: [1.0, 2.0], consumers: Vec::new() };
+       assert_eq!(locus.express(&Inheritance::Mendelian { dominance: 0.5 }), 1.5);
+       assert_eq!(locus.express(&Inheritance::Mendelian { dominance: 1.0 }), 2.0);
+       assert_eq!(locus.express(&Inheritance::Mendelian { dominance: 0.0 }), 1.0);
+   }
+
+   #[test]
+   fn test_child_alleles_come_from_parents() {
+       let mut rng = StdRng::seed_from_u64(3);
+       let params = stable();
+       let mother = Genetics::new(&params, &mut rng);
+       let father = Genetics::new(&params, &mut rng);
+       let child = mother.mix_with(&father, &params, 0.0, &mut rng);
+       for (i, locus) in child.genome.iter().enumerate() {
+           assert!(mother.genome[i].alleles.contains(&locus.alleles[0]));
+           assert!(father.genome[i].alleles.contains(&locus.alleles[1]));
+       }
+   }
+
+   #[test]
+   fn test_no_recombination_keeps_strands_together() {
+       let mut rng = StdRng::seed_from_u64(3);
+       let params = GeneticsParams {
+           recombination_rate: 0.0,
+           ..stable()
+       };
+       let mut parent = Genetics::new(&params, &mut rng);
+       for locus in parent.genome.iter_mut() {
+           locus.alleles = [1.0, 2.0];
+       }
+       for _ in 0..20 {
+           let child = parent.mix_with(&parent, &params, 0.0, &mut rng);
+           let first = child.genome[0].alleles[0];
+           assert!(child.genome.iter().all(|l| l.alleles[0] == first));
+       }
+   }
+
+   #[test]
+   fn test_mutation_changes_alleles() {
+       let mut rng = StdRng::seed_from_u64(3);
+       let params = GeneticsParams {
+           mutation_rate: 1.0,
+           ..Default::default()
+       };
+       let parent = Genetics::new(&params, &mut rng);
+       let child = parent.mix_with(&parent, &params, 0.0, &mut rng);
+       let inherited = |i: usize, a: f32| parent.genome[i].alleles.contains(&a);
+       assert!(child.genome.iter().enumerate().any(|(i, l)| !inherited(i, l.alleles[0])));
+   }
+
+   #[test]
+   fn test_inbreeding_depression() {
+       let params = stable();
+       let parent = Genetics::new(&params, &mut StdRng::seed_from_u64(7));
+       let outbred = parent.mix_with(&parent, &params, 0.0, &mut StdRng::seed_from_u64(1));
+       let inbred = parent.mix_with(&parent, &params, 0.2, &mut StdRng::seed_from_u64(1));
+       assert!(inbred.value(ENERGY).unwrap() < outbred.value(ENERGY).unwrap());
+   }
+
+   #[test]
+   fn test_configured_trait_feeds_hook() {
+       let mut registry = TraitRegistry::empty();
+       let mut aggression = TraitDef::new("aggression", &[hooks::HURT_DAMAGE]);
+       aggression.distribution = TraitDistribution::Constant { value: 2.0 };
+       aggression.inheritance = Inheritance::Blend;
+       registry.register(aggression);
+       let params = GeneticsParams {
+           traits: registry,
+           ..stable()
+       };
+
+       let parent = Genetics::new(&params, &mut StdRng::seed_from_u64(1));
+       assert_eq!(parent.factor(hooks::HURT_DAMAGE), 2.0);
+       assert_eq!(parent.factor(hooks::PLOT_SUCCESS), 1.0);
+       let child = parent.mix_with(&parent, &params, 0.0, &mut StdRng::seed_from_u64(1));
+       assert_eq!(child.value("aggression"), Some(2.0));
+   }
+
+   #[test]
+   fn test_only_fitness_traits_make_a_mate_attractive() {
+       let mut registry = TraitRegistry::default();
+       registry.register(TraitDef::new("aggression", &[hooks::HURT_DAMAGE]));
+       let params = GeneticsParams {
+           traits: registry,
+           ..stable()
+       };
+       let mut genetics = Genetics::new(&params, &mut StdRng::seed_from_u64(1));
+       let fitness = genetics.fitness();
+       genetics.set("aggression", 3.0);
+       assert_eq!(genetics.fitness(), fitness);
+       genetics.set(ENERGY, 3.0);
+       assert!(genetics.fitness() > fitness);
+
+       let empty = GeneticsParams {
+           traits: TraitRegistry::empty(),
+           ..stable()
+       };
+       assert_eq!(Genetics::new(&empty, &mut StdRng::seed_from_u64(1)).fitness(), 1.0);
+   }
+
+   #[test]
+   fn test_null_trait_is_a_bounded_divisor() {
+       let mut parent = Genetics::new(&GeneticsParams::default(), &mut StdRng::seed_from_u64(1));
+       parent.set(ENERGY, 0.0);
+       assert_eq!(parent.factor(hooks::STARVATION_RESISTANCE), 0.0);
+       assert_eq!(parent.divisor(hooks::STARVATION_RESISTANCE), MIN_DIVISOR);
+   }
+}
@@ -1,6 +1,11 @@
 pub mod estajo;
 pub mod event;
+pub mod genetics;
 pub mod needs;  // important: pub mod au lieu de mod
+pub mod position;
+pub mod stats;
+pub mod traits;
 pub use estajo::Estajo;
 pub use event::{Event, EventType};
-pub use needs::Needs;
\ No newline at end of file
+pub use needs::Needs;
+pub use position::Position;
\ No newline at end of file
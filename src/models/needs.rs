@@ -6,26 +6,62 @@ use rand::Rng;
 pub struct Needs {
    pub hunger: f32,     // 0-100
    pub ambition: f32,   // 0-100
+   #[serde(default)]
+   pub reproduction: f32,   // 0-100
 }
 
 impl Needs {
-   pub fn new() -> Self {
+   pub fn new(rng: &mut impl Rng) -> Self {
        Self {
            hunger: 100.0,
-           ambition: rand::thread_rng().gen_range(30.0..70.0),
+           ambition: rng.gen_range(30.0..70.0),
+           reproduction: 0.0,
        }
    }
 
-   pub fn update(&mut self) {
-       self.hunger -= 2.0;
+   /// La faim baisse de `drain`, l'ambition fluctue.
+   pub fn update(&mut self, drain: f32, rng: &mut impl Rng) {
+       self.hunger -= drain;
        self.hunger = self.hunger.max(0.0);
        
-       self.ambition += rand::thread_rng().gen_range(-1.0..2.0);
+       self.ambition += rng.gen_range(-1.0..2.0);
        self.ambition = self.ambition.clamp(0.0, 100.0);
    }
 
+   /// Le désir de se reproduire monte d'autant plus vite que l'estajo est
+   /// rassasié, et reste nul tant qu'il n'est pas fertile.
+   pub fn update_reproduction(&mut self, fertile: bool, rate: f32) {
+       if fertile {
+           self.reproduction += rate * self.hunger / 100.0;
+           self.reproduction = self.reproduction.clamp(0.0, 100.0);
+       } else {
+           self.reproduction = 0.0;
+       }
+   }
+
    pub fn eat(&mut self, amount: f32) {
        self.hunger += amount;
        self.hunger = self.hunger.min(100.0);
    }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+   use rand::SeedableRng;
+   use rand::rngs::StdRng;
+
+   #[test]
+   fn test_reproduction_drive_follows_satiety() {
+       let mut needs = Needs::new(&mut StdRng::seed_from_u64(1));
+       needs.update_reproduction(true, 10.0);
+       assert_eq!(needs.reproduction, 10.0);
+
+       needs.hunger = 50.0;
+       needs.update_reproduction(true, 10.0);
+       assert_eq!(needs.reproduction, 15.0);
+
+       needs.update_reproduction(false, 10.0);
+       assert_eq!(needs.reproduction, 0.0);
+   }
+}
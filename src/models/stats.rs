@@ -0,0 +1,98 @@
+// src/models/stats.rs
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use super::event::StateChange;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub enum Stat {
+    Energy,
+    Satisfaction,
+    Influence,
+}
+
+impl Stat {
+    pub const ALL: [Stat; 3] = [Stat::Energy, Stat::Satisfaction, Stat::Influence];
+
+    pub fn state_change(&self) -> StateChange {
+        match self {
+            Stat::Energy => StateChange::EnergyUpdate,
+            Stat::Satisfaction => StateChange::SatisfactionUpdate,
+            Stat::Influence => StateChange::InfluenceUpdate,
+        }
+    }
+}
+
+impl fmt::Display for Stat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stat::Energy => write!(f, "energy"),
+            Stat::Satisfaction => write!(f, "satisfaction"),
+            Stat::Influence => write!(f, "influence"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub enum StatLevel {
+    Low,
+    Normal,
+    High,
+}
+
+impl fmt::Display for StatLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatLevel::Low => write!(f, "low"),
+            StatLevel::Normal => write!(f, "normal"),
+            StatLevel::High => write!(f, "high"),
+        }
+    }
+}
+
+/// État courant d'un estajo, distinct de ses facteurs génétiques.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Stats {
+    pub energy: f32,        // 0-100
+    pub satisfaction: f32,  // 0-100
+    pub influence: f32,     // 0-100
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            energy: 100.0,
+            satisfaction: 50.0,
+            influence: 10.0,
+        }
+    }
+}
+
+impl Stats {
+    pub fn get(&self, stat: Stat) -> f32 {
+        match stat {
+            Stat::Energy => self.energy,
+            Stat::Satisfaction => self.satisfaction,
+            Stat::Influence => self.influence,
+        }
+    }
+
+    pub fn adjust(&mut self, stat: Stat, delta: f32) {
+        let value = match stat {
+            Stat::Energy => &mut self.energy,
+            Stat::Satisfaction => &mut self.satisfaction,
+            Stat::Influence => &mut self.influence,
+        };
+        *value = (*value + delta).clamp(0.0, 100.0);
+    }
+
+    pub fn level(&self, stat: Stat, low: f32, high: f32) -> StatLevel {
+        let value = self.get(stat);
+        if value < low {
+            StatLevel::Low
+        } else if value > high {
+            StatLevel::High
+        } else {
+            StatLevel::Normal
+        }
+    }
+}
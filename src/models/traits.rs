@@ -0,0 +1,161 @@
+// src/models/traits.rs
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+
+// Traits fournis par défaut
+pub const ENERGY: &str = "energy";
+pub const SATISFACTION: &str = "satisfaction";
+pub const INFLUENCE: &str = "influence";
+
+/// Points d'accroche où le moteur applique les traits génétiques. Un trait
+/// qui déclare un de ces consommateurs multiplie la grandeur correspondante.
+pub mod hooks {
+    pub const LIFESPAN: &str = "lifespan";
+    pub const STARVATION_RESISTANCE: &str = "starvation_resistance";
+    pub const HELP_HEAL: &str = "help_heal";
+    pub const HURT_DAMAGE: &str = "hurt_damage";
+    pub const PLOT_SUCCESS: &str = "plot_success";
+    pub const FOOD_COMPETITION: &str = "food_competition";
+    pub const FERTILITY: &str = "fertility";
+    pub const ENERGY_GAIN: &str = "energy_gain";
+    pub const SATISFACTION_GAIN: &str = "satisfaction_gain";
+    pub const INFLUENCE_GAIN: &str = "influence_gain";
+    pub const DISEASE_RESISTANCE: &str = "disease_resistance";
+    /// Aptitude perçue par les partenaires potentiels
+    pub const FITNESS: &str = "fitness";
+}
+
+/// Distribution des allèles chez les fondateurs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TraitDistribution {
+    Uniform { low: f32, high: f32 },
+    Constant { value: f32 },
+}
+
+impl TraitDistribution {
+    pub fn sample(&self, rng: &mut impl Rng) -> f32 {
+        match *self {
+            TraitDistribution::Uniform { low, high } if low < high => rng.gen_range(low..high),
+            TraitDistribution::Uniform { low, .. } => low,
+            TraitDistribution::Constant { value } => value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Inheritance {
+    /// Un allèle de chaque parent ; 0.5 = additif, 1 = l'allèle fort domine
+    Mendelian { dominance: f32 },
+    /// Les deux allèles de l'enfant sont la moyenne de ceux des parents
+    Blend,
+    /// Non héritable : tiré à nouveau de la distribution à chaque naissance
+    Random,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TraitDef {
+    pub name: String,
+    pub min: f32,
+    pub max: f32,
+    pub distribution: TraitDistribution,
+    pub inheritance: Inheritance,
+    /// Points d'accroche (voir `hooks`) modulés par ce trait
+    #[serde(default)]
+    pub consumers: Vec<String>,
+}
+
+impl TraitDef {
+    pub fn new(name: &str, consumers: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            min: 0.1,
+            max: 3.0,
+            distribution: TraitDistribution::Uniform { low: 0.8, high: 1.2 },
+            inheritance: Inheritance::Mendelian { dominance: 0.5 },
+            consumers: consumers.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+/// Traits héritables, dans l'ordre de leurs loci sur le chromosome.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct TraitRegistry {
+    traits: Vec<TraitDef>,
+}
+
+impl Default for TraitRegistry {
+    fn default() -> Self {
+        Self {
+            traits: vec![
+                TraitDef::new(ENERGY, &[
+                    hooks::LIFESPAN,
+                    hooks::STARVATION_RESISTANCE,
+                    hooks::HELP_HEAL,
+                    hooks::HURT_DAMAGE,
+                    hooks::FOOD_COMPETITION,
+                    hooks::ENERGY_GAIN,
+                    hooks::DISEASE_RESISTANCE,
+                    hooks::FITNESS,
+                ]),
+                TraitDef::new(SATISFACTION, &[hooks::SATISFACTION_GAIN, hooks::FITNESS]),
+                TraitDef::new(INFLUENCE, &[hooks::PLOT_SUCCESS, hooks::INFLUENCE_GAIN, hooks::FITNESS]),
+            ],
+        }
+    }
+}
+
+impl TraitRegistry {
+    pub fn empty() -> Self {
+        Self { traits: Vec::new() }
+    }
+
+    /// Ajoute un trait, ou remplace celui qui porte le même nom.
+    pub fn register(&mut self, def: TraitDef) {
+        match self.traits.iter_mut().find(|t| t.name == def.name) {
+            Some(existing) => *existing = def,
+            None => self.traits.push(def),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TraitDef> {
+        self.traits.iter().find(|t| t.name == name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TraitDef> {
+        self.traits.iter()
+    }
+
+    pub fn consumed_by<'a>(&'a self, hook: &'a str) -> impl Iterator<Item = &'a TraitDef> {
+        self.traits.iter().filter(move |t| t.consumers.iter().any(|c| c == hook))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_replaces_by_name() {
+        let mut registry = TraitRegistry::default();
+        registry.register(TraitDef::new("aggression", &[hooks::HURT_DAMAGE]));
+        registry.register(TraitDef::new(ENERGY, &[]));
+        assert_eq!(registry.iter().count(), 4);
+        let names: Vec<_> = registry.consumed_by(hooks::HURT_DAMAGE).map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["aggression"]);
+    }
+
+    #[test]
+    fn test_registry_from_configuration() {
+        let json = r#"[{
+            "name": "fertility", "min": 0.0, "max": 1.0,
+            "distribution": {"kind": "uniform", "low": 0.5, "high": 1.0},
+            "inheritance": {"kind": "blend"},
+            "consumers": ["fertility"]
+        }]"#;
+        let registry: TraitRegistry = serde_json::from_str(json).unwrap();
+        assert_eq!(registry.get("fertility").unwrap().inheritance, Inheritance::Blend);
+    }
+}
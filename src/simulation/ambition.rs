@@ -0,0 +1,147 @@
+// src/simulation/ambition.rs
+use std::fmt;
+use rand::Rng;
+use crate::models::estajo::Estajo;
+use crate::models::event::{Event, EventType, Need};
+use crate::models::stats::Stat;
+use super::parameters::AmbitionParams;
+
+/// Ce que poursuit un estajo ambitieux.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Goal {
+    /// Disputer son rang à un voisin plus influent
+    Challenge,
+    /// Comploter contre un rival
+    Plot,
+    /// Accaparer la nourriture alentour
+    Hoard,
+}
+
+impl fmt::Display for Goal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Goal::Challenge => write!(f, "challenge"),
+            Goal::Plot => write!(f, "plot"),
+            Goal::Hoard => write!(f, "hoard"),
+        }
+    }
+}
+
+/// Poids d'un estajo dans une épreuve de force : son influence.
+fn standing(estajo: &Estajo) -> f32 {
+    estajo.stats.influence.max(1.0)
+}
+
+/// Chance de l'emporter lors d'un défi, au prorata des influences.
+pub fn challenge_chance(challenger: &Estajo, rival: &Estajo) -> f32 {
+    standing(challenger) / (standing(challenger) + standing(rival))
+}
+
+/// Défi pour le rang : le vainqueur prend de l'influence au perdant.
+pub fn challenge(
+    challenger: &mut Estajo,
+    rival: &mut Estajo,
+    params: &AmbitionParams,
+    rng: &mut impl Rng,
+) -> bool {
+    let won = rng.gen::<f32>() < challenge_chance(challenger, rival);
+    let (winner, loser) = if won { (&mut *challenger, &mut *rival) } else { (&mut *rival, &mut *challenger) };
+    let stake = params.challenge_stake.min(loser.stats.influence);
+    loser.stats.adjust(Stat::Influence, -stake);
+    winner.stats.adjust(Stat::Influence, stake);
+
+    let summary = if won { "won" } else { "lost" };
+    challenger.add_event(Event::new(
+        EventType::Need(Need::Ambition),
+        format!("Challenged {} ({})", rival.name, summary)
+    ));
+    rival.add_event(Event::new(
+        EventType::Need(Need::Ambition),
+        format!("Challenged by {} ({})", challenger.name, summary)
+    ));
+    won
+}
+
+/// Part de la ration d'accaparement obtenue, selon l'influence.
+pub fn hoard_share(estajo: &Estajo) -> f32 {
+    (estajo.stats.influence / 50.0).clamp(0.2, 2.0)
+}
+
+/// L'ambition assouvie retombe et contente l'estajo.
+pub fn satisfy(estajo: &mut Estajo, goal: Goal, params: &AmbitionParams) {
+    estajo.needs.ambition = (estajo.needs.ambition - params.relief).max(0.0);
+    estajo.stats.adjust(Stat::Satisfaction, params.relief / 2.0);
+    estajo.add_event(Event::new(
+        EventType::Need(Need::Ambition),
+        format!("Ambition satisfied ({})", goal)
+    ));
+}
+
+/// L'ambition contrariée aigrit l'estajo et s'en trouve attisée.
+pub fn frustrate(estajo: &mut Estajo, goal: Goal, params: &AmbitionParams) {
+    estajo.needs.ambition = (estajo.needs.ambition + params.frustration / 2.0).min(100.0);
+    estajo.stats.adjust(Stat::Satisfaction, -params.frustration);
+    estajo.add_event(Event::new(
+        EventType::Need(Need::Ambition),
+        format!("Ambition frustrated ({})", goal)
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::test_support::pair;
+
+    #[test]
+    fn test_challenge_decided_by_influence() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (mut a, mut b) = pair(&mut rng);
+        a.stats.influence = 90.0;
+        b.stats.influence = 10.0;
+        assert!(challenge_chance(&a, &b) > 0.8);
+
+        let params = AmbitionParams::default();
+        let wins = (0..100)
+            .filter(|_| {
+                a.stats.influence = 90.0;
+                b.stats.influence = 10.0;
+                challenge(&mut a, &mut b, &params, &mut rng)
+            })
+            .count();
+        assert!(wins > 70);
+    }
+
+    #[test]
+    fn test_challenge_transfers_influence() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let (mut a, mut b) = pair(&mut rng);
+        a.stats.influence = 40.0;
+        b.stats.influence = 40.0;
+        let won = challenge(&mut a, &mut b, &AmbitionParams::default(), &mut rng);
+        let (winner, loser) = if won { (&a, &b) } else { (&b, &a) };
+        assert!(winner.stats.influence > 40.0);
+        assert!(loser.stats.influence < 40.0);
+        assert_eq!(a.history.len(), 1);
+        assert_eq!(b.history.len(), 1);
+    }
+
+    #[test]
+    fn test_satisfy_and_frustrate() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let (mut a, _) = pair(&mut rng);
+        let params = AmbitionParams::default();
+
+        a.needs.ambition = 90.0;
+        satisfy(&mut a, Goal::Hoard, &params);
+        assert!(a.needs.ambition < 90.0);
+        assert!(a.stats.satisfaction > 50.0);
+
+        a.needs.ambition = 80.0;
+        a.stats.satisfaction = 50.0;
+        frustrate(&mut a, Goal::Challenge, &params);
+        assert!(a.needs.ambition > 80.0);
+        assert!(a.stats.satisfaction < 50.0);
+    }
+}
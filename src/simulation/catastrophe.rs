@@ -0,0 +1,100 @@
+// src/simulation/catastrophe.rs
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::models::position::Position;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CatastropheKind {
+    /// La nourriture pourrit sur pied
+    Famine,
+    /// L'eau emporte la nourriture et blesse ceux qu'elle surprend
+    Flood,
+    /// Une maladie frappe d'un coup une partie de la population
+    Plague,
+    /// Un prédateur attaque une partie de la population
+    Predator,
+}
+
+impl fmt::Display for CatastropheKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatastropheKind::Famine => write!(f, "Famine"),
+            CatastropheKind::Flood => write!(f, "Flood"),
+            CatastropheKind::Plague => write!(f, "Plague"),
+            CatastropheKind::Predator => write!(f, "Predator"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Catastrophe {
+    pub kind: CatastropheKind,
+    /// Entre 0 et 1 : part de la nourriture détruite (famine, inondation),
+    /// ou part des estajoj touchés (épidémie, prédateur)
+    pub severity: f32,
+    /// Vie perdue par chaque estajo touché (inondation, prédateur)
+    #[serde(default)]
+    pub damage: f32,
+    /// Rayon de la zone touchée ; absent, le monde entier est touché
+    #[serde(default)]
+    pub radius: Option<f32>,
+    /// Centre de la zone touchée, tiré au hasard s'il est absent
+    #[serde(default)]
+    pub center: Option<Position>,
+    /// Maladie propagée par une épidémie, la première configurée si absente
+    #[serde(default)]
+    pub disease: Option<String>,
+}
+
+impl Catastrophe {
+    pub fn new(kind: CatastropheKind, severity: f32) -> Self {
+        Self {
+            kind,
+            severity,
+            damage: 0.0,
+            radius: None,
+            center: None,
+            disease: None,
+        }
+    }
+}
+
+/// Catastrophe prévue par le scénario à un tick donné.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScheduledCatastrophe {
+    pub tick: u32,
+    #[serde(flatten)]
+    pub catastrophe: Catastrophe,
+}
+
+/// Catastrophe qui peut survenir à n'importe quel tick.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RandomCatastrophe {
+    /// Chance par tick
+    pub chance: f32,
+    #[serde(flatten)]
+    pub catastrophe: Catastrophe,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::parameters::CatastropheParams;
+
+    #[test]
+    fn test_scenario_from_configuration() {
+        let json = r#"{
+            "scheduled": [
+                {"tick": 100, "kind": "flood", "severity": 0.5, "damage": 20.0, "radius": 3.0},
+                {"tick": 200, "kind": "famine", "severity": 0.8}
+            ],
+            "random": [{"chance": 0.01, "kind": "predator", "severity": 0.2, "damage": 40.0}]
+        }"#;
+        let params: CatastropheParams = serde_json::from_str(json).unwrap();
+        assert_eq!(params.scheduled[0].catastrophe.kind, CatastropheKind::Flood);
+        assert_eq!(params.scheduled[0].catastrophe.radius, Some(3.0));
+        assert_eq!(params.scheduled[1].catastrophe, Catastrophe::new(CatastropheKind::Famine, 0.8));
+        assert_eq!(params.random[0].catastrophe.damage, 40.0);
+    }
+}
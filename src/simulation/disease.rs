@@ -0,0 +1,219 @@
+// src/simulation/disease.rs
+use std::fmt;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::models::estajo::{Estajo, Infection};
+use crate::models::event::{DeathCause, Event, EventType, StateChange};
+use crate::models::traits::hooks;
+
+/// Maladie transmissible : incubation silencieuse, puis symptômes
+/// contagieux jusqu'à la guérison, suivie d'une immunité temporaire.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Disease {
+    pub name: String,
+    /// Chance de transmission lors d'une interaction ou d'un accouplement
+    pub contact_transmission: f32,
+    /// Chance par tick de contaminer chaque voisin à portée d'interaction
+    pub proximity_transmission: f32,
+    /// Ticks d'incubation, pendant lesquels l'estajo n'est ni malade ni contagieux
+    pub incubation: u32,
+    /// Ticks de maladie avant la guérison
+    pub duration: u32,
+    /// Vie perdue par tick de maladie, divisée par la résistance
+    pub damage: f32,
+    /// Ticks d'immunité après la guérison
+    pub immunity: u32,
+    /// Fondateurs infectés au départ
+    pub initial_cases: u32,
+    /// Chance par tick qu'un estajo au hasard contracte la maladie sans contact
+    pub outbreak_chance: f32,
+}
+
+impl Disease {
+    /// Fièvre d'exemple, contagieuse mais rarement mortelle. Aucune maladie
+    /// ne circule par défaut : l'ajouter à `DiseaseParams::diseases` pour
+    /// l'activer.
+    pub fn fever() -> Self {
+        Self {
+            name: "fever".to_string(),
+            contact_transmission: 0.3,
+            proximity_transmission: 0.02,
+            incubation: 5,
+            duration: 10,
+            damage: 1.0,
+            immunity: 100,
+            initial_cases: 0,
+            outbreak_chance: 0.005,
+        }
+    }
+}
+
+impl Default for Disease {
+    fn default() -> Self {
+        Self::fever()
+    }
+}
+
+/// Stade d'une infection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    Incubating,
+    Sick,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Incubating => write!(f, "incubating"),
+            Phase::Sick => write!(f, "sick"),
+        }
+    }
+}
+
+pub fn phase(infection: &Infection, disease: &Disease) -> Phase {
+    if infection.elapsed < disease.incubation { Phase::Incubating } else { Phase::Sick }
+}
+
+/// Résistance génétique : divise les chances d'être contaminé et les dégâts.
+pub fn resistance(estajo: &Estajo) -> f32 {
+    estajo.genetics.divisor(hooks::DISEASE_RESISTANCE)
+}
+
+/// Ni déjà infecté, ni immunisé contre cette maladie.
+pub fn is_susceptible(estajo: &Estajo, disease: &Disease) -> bool {
+    estajo.is_alive() && estajo.infection.is_none() && !estajo.immunity.contains_key(&disease.name)
+}
+
+pub fn is_contagious(estajo: &Estajo, disease: &Disease) -> bool {
+    estajo.infection.as_ref()
+        .is_some_and(|i| i.disease == disease.name && phase(i, disease) == Phase::Sick)
+}
+
+/// Expose un estajo à la maladie avec la chance donnée, réduite par sa
+/// résistance. Retourne `true` s'il est contaminé.
+pub fn expose(estajo: &mut Estajo, disease: &Disease, chance: f32, rng: &mut impl Rng) -> bool {
+    if !is_susceptible(estajo, disease) || rng.gen::<f32>() >= chance / resistance(estajo) {
+        return false;
+    }
+    infect(estajo, disease);
+    true
+}
+
+/// Contamine l'estajo sans condition.
+pub fn infect(estajo: &mut Estajo, disease: &Disease) {
+    estajo.infection = Some(Infection { disease: disease.name.clone(), elapsed: 0 });
+    estajo.add_event(Event::new(
+        EventType::StateChange(StateChange::Infection),
+        format!("Caught {}", disease.name)
+    ));
+}
+
+/// Fait avancer l'infection d'un tick : les malades perdent de la vie,
+/// puis guérissent et s'immunisent. Retourne `true` à la guérison.
+pub fn progress(estajo: &mut Estajo, disease: &Disease) -> bool {
+    let Some(infection) = estajo.infection.as_mut() else { return false };
+    infection.elapsed += 1;
+    if phase(infection, disease) == Phase::Sick {
+        estajo.take_damage(disease.damage / resistance(estajo), DeathCause::Disease);
+    }
+    let cured = estajo.infection.as_ref()
+        .is_some_and(|i| i.elapsed >= disease.incubation + disease.duration);
+    if !cured || !estajo.is_alive() {
+        return false;
+    }
+    estajo.infection = None;
+    if disease.immunity > 0 {
+        estajo.immunity.insert(disease.name.clone(), disease.immunity);
+    }
+    estajo.add_event(Event::new(
+        EventType::StateChange(StateChange::Recovery),
+        format!("Recovered from {}", disease.name)
+    ));
+    true
+}
+
+/// L'immunité s'estompe d'un tick.
+pub fn wane(estajo: &mut Estajo) {
+    for ticks in estajo.immunity.values_mut() {
+        *ticks = ticks.saturating_sub(1);
+    }
+    estajo.immunity.retain(|_, ticks| *ticks > 0);
+}
+
+/// Bilan d'une maladie pendant un tick.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InfectionCounts {
+    /// Contaminations survenues pendant le tick
+    pub new_cases: u32,
+    pub incubating: u32,
+    pub sick: u32,
+    pub recovered: u32,
+    pub deaths: u32,
+    /// Estajoj immunisés en fin de tick
+    pub immune: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::models::traits::ENERGY;
+    use crate::test_support::estajo;
+
+    fn flu() -> Disease {
+        Disease {
+            name: "flu".to_string(),
+            incubation: 2,
+            duration: 3,
+            immunity: 4,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_infection_runs_its_course() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut estajo = estajo(0, &mut rng);
+        let disease = flu();
+        assert!(expose(&mut estajo, &disease, 100.0, &mut rng));
+        assert!(!expose(&mut estajo, &disease, 100.0, &mut rng));
+
+        // Incubation silencieuse, puis maladie contagieuse et douloureuse
+        progress(&mut estajo, &disease);
+        assert!(!is_contagious(&estajo, &disease));
+        assert_eq!(estajo.life, 100.0);
+        progress(&mut estajo, &disease);
+        assert!(is_contagious(&estajo, &disease));
+        assert!(estajo.life < 100.0);
+
+        let cured = (0..3).filter(|_| progress(&mut estajo, &disease)).count();
+        assert_eq!(cured, 1);
+        assert!(estajo.infection.is_none());
+        assert!(!is_susceptible(&estajo, &disease));
+
+        // L'immunité finit par s'estomper
+        for _ in 0..disease.immunity {
+            wane(&mut estajo);
+        }
+        assert!(is_susceptible(&estajo, &disease));
+    }
+
+    #[test]
+    fn test_resistance_reduces_contagion() {
+        let disease = flu();
+        let caught = |resistance: f32| {
+            let mut rng = StdRng::seed_from_u64(2);
+            (0..200)
+                .filter(|_| {
+                    let mut estajo = estajo(0, &mut rng);
+                    estajo.genetics.set(ENERGY, resistance);
+                    expose(&mut estajo, &disease, 0.5, &mut rng)
+                })
+                .count()
+        };
+        let frail = caught(0.5);
+        let hardy = caught(2.0);
+        assert!(frail > hardy * 2);
+    }
+}
@@ -0,0 +1,172 @@
+// src/simulation/environment.rs
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use super::parameters::EnvironmentParams;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub const ALL: [Season; 4] = [Season::Spring, Season::Summer, Season::Autumn, Season::Winter];
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Season::Spring => write!(f, "Spring"),
+            Season::Summer => write!(f, "Summer"),
+            Season::Autumn => write!(f, "Autumn"),
+            Season::Winter => write!(f, "Winter"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Daylight {
+    Day,
+    Night,
+}
+
+impl fmt::Display for Daylight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Daylight::Day => write!(f, "Day"),
+            Daylight::Night => write!(f, "Night"),
+        }
+    }
+}
+
+/// Multiplicateurs appliqués par l'environnement aux rythmes du monde.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Modifiers {
+    /// Repousse de la nourriture
+    pub regrowth: f32,
+    /// Faim perdue par tick
+    pub hunger: f32,
+    /// Chance de concevoir
+    pub fertility: f32,
+    /// Vie perdue en vieillissant et en mourant de faim
+    pub mortality: f32,
+}
+
+impl Modifiers {
+    pub fn new(regrowth: f32, hunger: f32, fertility: f32, mortality: f32) -> Self {
+        Self { regrowth, hunger, fertility, mortality }
+    }
+
+    /// Effet cumulé de deux cycles.
+    pub fn combine(&self, other: &Modifiers) -> Self {
+        Self {
+            regrowth: self.regrowth * other.regrowth,
+            hunger: self.hunger * other.hunger,
+            fertility: self.fertility * other.fertility,
+            mortality: self.mortality * other.mortality,
+        }
+    }
+}
+
+impl Default for Modifiers {
+    fn default() -> Self {
+        Self::new(1.0, 1.0, 1.0, 1.0)
+    }
+}
+
+/// État de l'environnement à un tick donné.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Environment {
+    /// `None` quand les saisons sont désactivées
+    pub season: Option<Season>,
+    /// Tick écoulés depuis le début de la saison
+    pub season_tick: u32,
+    /// `None` quand l'alternance du jour et de la nuit est désactivée
+    pub daylight: Option<Daylight>,
+}
+
+impl Environment {
+    pub fn at(tick: u32, params: &EnvironmentParams) -> Self {
+        let (season, season_tick) = match params.season_length {
+            0 => (None, 0),
+            length => (Some(Season::ALL[(tick / length) as usize % 4]), tick % length),
+        };
+        let daylight = match params.day_length {
+            0 => None,
+            length if tick % length < length / 2 => Some(Daylight::Day),
+            _ => Some(Daylight::Night),
+        };
+        Self { season, season_tick, daylight }
+    }
+
+    pub fn modifiers(&self, params: &EnvironmentParams) -> Modifiers {
+        let season = match self.season {
+            Some(Season::Spring) => params.spring,
+            Some(Season::Summer) => params.summer,
+            Some(Season::Autumn) => params.autumn,
+            Some(Season::Winter) => params.winter,
+            None => Modifiers::default(),
+        };
+        let daylight = match self.daylight {
+            Some(Daylight::Day) => params.day,
+            Some(Daylight::Night) => params.night,
+            None => Modifiers::default(),
+        };
+        season.combine(&daylight)
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.season {
+            Some(season) => write!(f, "{} (day {})", season, self.season_tick + 1)?,
+            None => write!(f, "No seasons")?,
+        }
+        if let Some(daylight) = self.daylight {
+            write!(f, " - {}", daylight)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycles() {
+        let params = EnvironmentParams {
+            season_length: 10,
+            day_length: 4,
+            ..Default::default()
+        };
+        assert_eq!(Environment::at(0, &params).season, Some(Season::Spring));
+        assert_eq!(Environment::at(25, &params).season, Some(Season::Autumn));
+        assert_eq!(Environment::at(35, &params).season, Some(Season::Winter));
+        assert_eq!(Environment::at(40, &params).season, Some(Season::Spring));
+        assert_eq!(Environment::at(1, &params).daylight, Some(Daylight::Day));
+        assert_eq!(Environment::at(2, &params).daylight, Some(Daylight::Night));
+
+        let still = EnvironmentParams { season_length: 0, day_length: 0, ..params };
+        let environment = Environment::at(35, &still);
+        assert_eq!(environment.season, None);
+        assert_eq!(environment.modifiers(&still), Modifiers::default());
+    }
+
+    #[test]
+    fn test_winter_nights_are_harsh() {
+        let params = EnvironmentParams {
+            season_length: 10,
+            day_length: 2,
+            ..Default::default()
+        };
+        let summer_night = Environment::at(11, &params).modifiers(&params);
+        let winter_night = Environment::at(31, &params).modifiers(&params);
+        assert!(winter_night.regrowth < summer_night.regrowth);
+        assert!(winter_night.hunger > summer_night.hunger);
+        assert!(winter_night.mortality > summer_night.mortality);
+        assert_eq!(winter_night, params.winter.combine(&params.night));
+    }
+}
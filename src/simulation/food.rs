@@ -0,0 +1,144 @@
+// src/simulation/food.rs
+use serde::{Deserialize, Serialize};
+use crate::models::position::Position;
+use super::space::Grid;
+
+/// Réserve de nourriture commune, qui repousse jusqu'à sa capacité.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FoodStock {
+    pub amount: f32,
+    pub capacity: f32,
+}
+
+impl FoodStock {
+    pub fn new(amount: f32, capacity: f32) -> Self {
+        Self {
+            amount: amount.clamp(0.0, capacity),
+            capacity,
+        }
+    }
+
+    /// Repousse d'une fraction `rate` de ce qui manque pour atteindre la capacité.
+    pub fn regrow(&mut self, rate: f32) -> f32 {
+        let growth = (self.capacity - self.amount).max(0.0) * rate;
+        self.amount += growth;
+        growth
+    }
+
+    /// Prélève au plus `wanted` et retourne la quantité obtenue.
+    pub fn take(&mut self, wanted: f32) -> f32 {
+        let taken = wanted.clamp(0.0, self.amount);
+        self.amount -= taken;
+        taken
+    }
+
+    /// Détruit une fraction de la réserve et retourne la quantité perdue.
+    pub fn spoil(&mut self, fraction: f32) -> f32 {
+        let lost = self.amount * fraction.clamp(0.0, 1.0);
+        self.amount -= lost;
+        lost
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.amount <= 0.0
+    }
+}
+
+/// Une réserve par case de la grille : on ne mange que ce qui pousse à portée.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FoodField {
+    grid: Grid,
+    cells: Vec<FoodStock>,
+}
+
+impl FoodField {
+    pub fn new(grid: Grid, amount_per_cell: f32, capacity_per_cell: f32) -> Self {
+        Self {
+            grid,
+            cells: vec![FoodStock::new(amount_per_cell, capacity_per_cell); grid.cells()],
+        }
+    }
+
+    pub fn total(&self) -> f32 {
+        self.cells.iter().map(|c| c.amount).sum()
+    }
+
+    pub fn capacity(&self) -> f32 {
+        self.cells.iter().map(|c| c.capacity).sum()
+    }
+
+    pub fn at(&self, pos: Position) -> &FoodStock {
+        &self.cells[self.grid.index(pos)]
+    }
+
+    pub fn at_mut(&mut self, pos: Position) -> &mut FoodStock {
+        let index = self.grid.index(pos);
+        &mut self.cells[index]
+    }
+
+    /// Détruit une fraction de la nourriture des cases données.
+    pub fn spoil(&mut self, cells: &[Position], fraction: f32) -> f32 {
+        cells.iter().map(|&pos| self.at_mut(pos).spoil(fraction)).sum()
+    }
+
+    pub fn regrow(&mut self, rate: f32) -> f32 {
+        self.cells.iter_mut().map(|c| c.regrow(rate)).sum()
+    }
+
+    /// Case la plus fournie à portée de `center`.
+    pub fn richest_within(&self, center: Position, radius: f32) -> Option<Position> {
+        self.grid.within(center, radius)
+            .into_iter()
+            .filter(|&pos| !self.at(pos).is_empty())
+            .max_by(|&a, &b| self.at(a).amount.total_cmp(&self.at(b).amount))
+    }
+
+    /// Récolte jusqu'à `wanted` dans les cases à portée, les plus fournies d'abord.
+    pub fn gather(&mut self, center: Position, radius: f32, wanted: f32) -> f32 {
+        let mut cells = self.grid.within(center, radius);
+        cells.sort_by(|&a, &b| self.at(b).amount.total_cmp(&self.at(a).amount));
+
+        let mut gathered = 0.0;
+        for pos in cells {
+            if gathered >= wanted {
+                break;
+            }
+            gathered += self.at_mut(pos).take(wanted - gathered);
+        }
+        gathered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regrow_towards_capacity() {
+        let mut stock = FoodStock::new(0.0, 100.0);
+        stock.regrow(0.5);
+        assert_eq!(stock.amount, 50.0);
+        for _ in 0..100 {
+            stock.regrow(0.5);
+        }
+        assert!(stock.amount <= 100.0);
+    }
+
+    #[test]
+    fn test_take_is_bounded() {
+        let mut stock = FoodStock::new(20.0, 100.0);
+        assert_eq!(stock.take(30.0), 20.0);
+        assert!(stock.is_empty());
+        assert_eq!(stock.take(10.0), 0.0);
+    }
+
+    #[test]
+    fn test_gather_is_local() {
+        let grid = Grid::new(10, 1);
+        let mut field = FoodField::new(grid, 10.0, 10.0);
+        assert_eq!(field.gather(Position::new(0, 0), 1.0, 50.0), 20.0);
+        assert_eq!(field.total(), 80.0);
+        assert_eq!(field.richest_within(Position::new(0, 0), 1.0), None);
+        assert!(field.richest_within(Position::new(0, 0), 2.0).is_some());
+    }
+}
@@ -0,0 +1,237 @@
+// src/simulation/groups.rs
+use std::collections::{BTreeMap, BTreeSet};
+use crate::models::event::{Event, EventType, GroupChange};
+use super::lineage::Lineage;
+use super::parameters::GroupParams;
+use super::relationships::Relationships;
+
+/// Générations remontées pour la parenté des groupes : au-delà des
+/// arrière-grands-parents, elle ne pèse presque plus dans le lien.
+const KINSHIP_DEPTH: u32 = 3;
+
+/// Force du lien entre deux estajoj : affinité mutuelle moyenne, renforcée
+/// par la parenté, donnée par leur coefficient de consanguinité (un parent et
+/// son enfant, deux frères, comptent pour `kinship_bond`).
+pub fn bond(a: u32, b: u32, relationships: &Relationships, kinship: f32, params: &GroupParams) -> f32 {
+    let affinity = (relationships.get(a, b).affinity + relationships.get(b, a).affinity) / 2.0;
+    affinity + params.kinship_bond * (4.0 * kinship).min(1.0)
+}
+
+/// Groupes d'estajoj liés entre eux. Un groupe garde son identifiant tant
+/// que la majorité de ses membres restent ensemble.
+#[derive(Debug, Default, Clone)]
+pub struct Groups {
+    groups: BTreeMap<u32, BTreeSet<u32>>,
+    next_id: u32,
+}
+
+impl Groups {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn group_of(&self, id: u32) -> Option<u32> {
+        self.groups.iter()
+            .find(|(_, members)| members.contains(&id))
+            .map(|(&group, _)| group)
+    }
+
+    pub fn members(&self, group: u32) -> Option<&BTreeSet<u32>> {
+        self.groups.get(&group)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&u32, &BTreeSet<u32>)> {
+        self.groups.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    fn allocate_id(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Recompose les groupes à partir des liens du moment : les composantes
+    /// connexes des liens assez forts. Retourne les formations, scissions,
+    /// fusions et dissolutions survenues.
+    pub fn update(
+        &mut self,
+        ids: &[u32],
+        relationships: &Relationships,
+        lineage: &Lineage,
+        params: &GroupParams,
+    ) -> Vec<Event> {
+        // Seuls les estajoj qui se connaissent ou sont parents peuvent être liés
+        let living: BTreeSet<u32> = ids.iter().copied().collect();
+        let kinship = lineage.kinship(ids, KINSHIP_DEPTH);
+        let mut pairs: BTreeSet<(u32, u32)> = relationships.pairs()
+            .filter(|(a, b)| a != b && living.contains(a) && living.contains(b))
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+        pairs.extend(kinship.keys().copied());
+        let mut links: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for (a, b) in pairs {
+            let kin = kinship.get(&(a, b)).copied().unwrap_or(0.0);
+            if bond(a, b, relationships, kin, params) >= params.bond_threshold {
+                links.entry(a).or_default().push(b);
+                links.entry(b).or_default().push(a);
+            }
+        }
+        let mut components = components(ids, &links);
+        components.retain(|c| c.len() >= params.min_size.max(2));
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then(a.first().cmp(&b.first())));
+
+        let mut events = Vec::new();
+        let mut groups = BTreeMap::new();
+        for component in components {
+            // Le groupe le plus représenté transmet son identifiant
+            let mut overlaps: Vec<(u32, usize)> = self.groups.iter()
+                .map(|(&group, members)| (group, members.intersection(&component).count()))
+                .filter(|(_, overlap)| *overlap > 0)
+                .collect();
+            overlaps.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            let heir = overlaps.iter()
+                .map(|(group, _)| *group)
+                .find(|group| !groups.contains_key(group));
+
+            let id = match heir {
+                Some(group) => group,
+                None => {
+                    let id = self.allocate_id();
+                    let (change, details) = match overlaps.first() {
+                        Some((origin, _)) => (GroupChange::Split, format!("Group_{} split from Group_{}", id, origin)),
+                        None => (GroupChange::Formation, format!("Group_{} formed: {}", id, names(&component))),
+                    };
+                    events.push(Event::new(EventType::Group(change), details));
+                    id
+                }
+            };
+            groups.insert(id, component);
+        }
+
+        for (&old, members) in &self.groups {
+            if groups.contains_key(&old) {
+                continue;
+            }
+            let absorber = groups.iter()
+                .map(|(&group, current)| (group, current.intersection(members).count()))
+                .filter(|(_, overlap)| *overlap > 0)
+                .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)));
+            events.push(match absorber {
+                Some((group, _)) => Event::new(
+                    EventType::Group(GroupChange::Merge),
+                    format!("Group_{} absorbed Group_{}", group, old)
+                ),
+                None => Event::new(
+                    EventType::Group(GroupChange::Dissolution),
+                    format!("Group_{} dissolved", old)
+                ),
+            });
+        }
+
+        self.groups = groups;
+        events
+    }
+}
+
+fn names(members: &BTreeSet<u32>) -> String {
+    members.iter().map(|id| format!("Estajo_{}", id)).collect::<Vec<_>>().join(", ")
+}
+
+/// Composantes connexes du graphe dont les arêtes sont données par `links`.
+fn components(ids: &[u32], links: &BTreeMap<u32, Vec<u32>>) -> Vec<BTreeSet<u32>> {
+    let mut seen = BTreeSet::new();
+    let mut components = Vec::new();
+    for &start in ids {
+        if !seen.insert(start) {
+            continue;
+        }
+        let mut component = BTreeSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(current) = stack.pop() {
+            for &other in links.get(&current).into_iter().flatten() {
+                if seen.insert(other) {
+                    component.insert(other);
+                    stack.push(other);
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::Action;
+    use crate::simulation::parameters::RelationshipParams;
+
+    fn befriend(relationships: &mut Relationships, a: u32, b: u32) {
+        for _ in 0..5 {
+            relationships.record_action(&Action::Help, a, b, true, &[], &RelationshipParams::default());
+            relationships.record_action(&Action::Help, b, a, true, &[], &RelationshipParams::default());
+        }
+    }
+
+    fn kinds(events: &[Event]) -> Vec<EventType> {
+        events.iter().map(|e| e.event_type.clone()).collect()
+    }
+
+    #[test]
+    fn test_kin_form_a_group() {
+        let mut lineage = Lineage::new();
+        lineage.register(0, &[]);
+        lineage.register(1, &[]);
+        lineage.register(2, &[0, 1]);
+        lineage.register(3, &[]);
+        let mut groups = Groups::new();
+        let events = groups.update(&[0, 1, 2, 3], &Relationships::new(), &lineage, &GroupParams::default());
+        assert_eq!(kinds(&events), vec![EventType::Group(GroupChange::Formation)]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups.group_of(0), groups.group_of(2));
+        assert_eq!(groups.group_of(3), None);
+    }
+
+    #[test]
+    fn test_groups_split_merge_and_dissolve() {
+        let lineage = Lineage::new();
+        let params = GroupParams::default();
+        let ids = [0, 1, 2, 3];
+        let mut relationships = Relationships::new();
+        befriend(&mut relationships, 0, 1);
+        befriend(&mut relationships, 1, 2);
+        befriend(&mut relationships, 2, 3);
+        let mut groups = Groups::new();
+        groups.update(&ids, &relationships, &lineage, &params);
+        assert_eq!(groups.len(), 1);
+        let first = groups.group_of(0).unwrap();
+
+        // Le lien entre 1 et 2 se rompt : le groupe se scinde
+        relationships.record_action(&Action::Hurt, 1, 2, true, &[], &RelationshipParams::default());
+        relationships.record_action(&Action::Hurt, 2, 1, true, &[], &RelationshipParams::default());
+        relationships.decay(0.5);
+        let events = groups.update(&ids, &relationships, &lineage, &params);
+        assert_eq!(kinds(&events), vec![EventType::Group(GroupChange::Split)]);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups.group_of(0), Some(first));
+
+        // Réconciliation : les deux groupes fusionnent
+        befriend(&mut relationships, 1, 2);
+        let events = groups.update(&ids, &relationships, &lineage, &params);
+        assert_eq!(kinds(&events), vec![EventType::Group(GroupChange::Merge)]);
+        assert_eq!(groups.group_of(3), Some(first));
+
+        // Tout le monde s'est oublié
+        let events = groups.update(&ids, &Relationships::new(), &lineage, &params);
+        assert_eq!(kinds(&events), vec![EventType::Group(GroupChange::Dissolution)]);
+        assert!(groups.is_empty());
+    }
+}
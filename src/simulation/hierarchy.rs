@@ -0,0 +1,164 @@
+// src/simulation/hierarchy.rs
+use std::collections::{BTreeMap, BTreeSet};
+use crate::models::estajo::Estajo;
+use super::parameters::HierarchyParams;
+use super::relationships::{self, Relationships};
+
+/// Statut d'un estajo : son influence, gagnée par les complots réussis,
+/// l'aide apportée et les défis remportés, complétée par sa popularité
+/// (voir `Relationships::popularities`).
+pub fn status(estajo: &Estajo, popularity: f32, params: &HierarchyParams) -> f32 {
+    estajo.stats.influence + params.popularity_weight * popularity
+}
+
+/// Classement des estajoj par statut, chefs et suiveurs.
+#[derive(Debug, Default, Clone)]
+pub struct Hierarchy {
+    ranking: Vec<(u32, f32)>,
+    /// Chefs, par statut décroissant
+    heads: Vec<u32>,
+    /// Chef de chaque suiveur
+    leaders: BTreeMap<u32, u32>,
+}
+
+impl Hierarchy {
+    /// Les estajoj dont le statut atteint `leader_status` deviennent des
+    /// chefs, et le restent tant qu'il ne retombe pas sous `demotion_status` ;
+    /// chacun des autres suit le chef proche le plus prestigieux qu'il ne
+    /// déteste pas.
+    pub fn update(
+        &mut self,
+        estajoj: &BTreeMap<u32, Estajo>,
+        relationships: &Relationships,
+        params: &HierarchyParams,
+        bias: f32,
+    ) {
+        let popularity = relationships.popularities();
+        let mut ranking: Vec<(u32, f32)> = estajoj.values()
+            .filter(|e| e.is_alive())
+            .map(|e| (e.id, status(e, popularity.get(&e.id).copied().unwrap_or(0.0), params)))
+            .collect();
+        ranking.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let former: BTreeSet<u32> = self.heads.iter().copied().collect();
+        let heads: Vec<(u32, f32)> = ranking.iter()
+            .copied()
+            .filter(|(id, s)| {
+                *s >= params.leader_status
+                    || (*s >= params.demotion_status && former.contains(id))
+            })
+            .collect();
+        let leading: BTreeSet<u32> = heads.iter().map(|(id, _)| *id).collect();
+        let mut leaders = BTreeMap::new();
+        for &(id, _) in &ranking {
+            if leading.contains(&id) {
+                continue;
+            }
+            let position = estajoj[&id].position;
+            let leader = heads.iter()
+                .filter(|(head, _)| estajoj[head].position.distance(&position) <= params.follow_radius)
+                .map(|&(head, s)| {
+                    let rel = relationships.get(id, head);
+                    (head, s as f64 * relationships::weight(rel.affinity - rel.grudge, bias), rel.affinity)
+                })
+                .filter(|(_, _, affinity)| *affinity >= 0.0)
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((head, _, _)) = leader {
+                leaders.insert(id, head);
+            }
+        }
+        self.heads = heads.into_iter().map(|(id, _)| id).collect();
+        self.ranking = ranking;
+        self.leaders = leaders;
+    }
+
+    /// Estajoj du plus haut au plus bas statut.
+    pub fn ranking(&self) -> &[(u32, f32)] {
+        &self.ranking
+    }
+
+    pub fn status(&self, id: u32) -> f32 {
+        self.ranking.iter().find(|(other, _)| *other == id).map(|(_, s)| *s).unwrap_or(0.0)
+    }
+
+    /// Rang à partir de 1, `None` pour un inconnu.
+    pub fn rank(&self, id: u32) -> Option<usize> {
+        self.ranking.iter().position(|(other, _)| *other == id).map(|r| r + 1)
+    }
+
+    pub fn leader_of(&self, id: u32) -> Option<u32> {
+        self.leaders.get(&id).copied()
+    }
+
+    pub fn followers(&self, leader: u32) -> Vec<u32> {
+        self.leaders.iter()
+            .filter(|(_, &head)| head == leader)
+            .map(|(&id, _)| id)
+            .collect()
+    }
+
+    pub fn is_leader(&self, id: u32) -> bool {
+        self.heads.contains(&id)
+    }
+
+    /// Chefs par statut décroissant.
+    pub fn leaders(&self) -> &[u32] {
+        &self.heads
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::Action;
+    use crate::models::position::Position;
+    use crate::simulation::parameters::RelationshipParams;
+    use crate::test_support;
+
+    fn population(influences: &[f32]) -> BTreeMap<u32, Estajo> {
+        test_support::population(influences, |e, &influence| {
+            e.stats.influence = influence;
+            e.position = Position::new(e.id, 0);
+        })
+    }
+
+    #[test]
+    fn test_ranking_follows_influence() {
+        let mut estajoj = population(&[10.0, 60.0, 20.0]);
+        let params = HierarchyParams::default();
+        let mut hierarchy = Hierarchy::default();
+        hierarchy.update(&estajoj, &Relationships::new(), &params, 1.0);
+        let order: Vec<u32> = hierarchy.ranking().iter().map(|(id, _)| *id).collect();
+        assert_eq!(order, vec![1, 2, 0]);
+        assert_eq!(hierarchy.rank(1), Some(1));
+        assert_eq!(hierarchy.leaders(), &[1]);
+        assert!(hierarchy.is_leader(1));
+        assert!(!hierarchy.is_leader(2));
+
+        // Un chef garde sa place jusqu'au seuil de destitution
+        estajoj.get_mut(&1).unwrap().stats.influence = params.demotion_status;
+        estajoj.get_mut(&2).unwrap().stats.influence = params.leader_status - 1.0;
+        hierarchy.update(&estajoj, &Relationships::new(), &params, 1.0);
+        assert_eq!(hierarchy.leaders(), &[1]);
+        estajoj.get_mut(&1).unwrap().stats.influence = params.demotion_status - 1.0;
+        hierarchy.update(&estajoj, &Relationships::new(), &params, 1.0);
+        assert!(hierarchy.leaders().is_empty());
+    }
+
+    #[test]
+    fn test_followers_pick_a_leader_they_do_not_hate() {
+        let estajoj = population(&[10.0, 60.0, 50.0, 5.0]);
+        let params = HierarchyParams::default();
+        let mut relationships = Relationships::new();
+        let mut hierarchy = Hierarchy::default();
+        hierarchy.update(&estajoj, &relationships, &params, 1.0);
+        assert_eq!(hierarchy.leader_of(0), Some(1));
+        assert_eq!(hierarchy.leader_of(1), None);
+        assert_eq!(hierarchy.followers(1), vec![0, 3]);
+
+        // 0 a été blessé par 1 : il se range derrière 2
+        relationships.record_action(&Action::Hurt, 1, 0, true, &[], &RelationshipParams::default());
+        hierarchy.update(&estajoj, &relationships, &params, 1.0);
+        assert_eq!(hierarchy.leader_of(0), Some(2));
+    }
+}
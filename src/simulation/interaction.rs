@@ -0,0 +1,140 @@
+// src/simulation/interaction.rs
+use rand::Rng;
+use crate::models::estajo::Estajo;
+use crate::models::event::{Action, DeathCause, Event, EventType};
+use crate::models::traits::hooks;
+use super::parameters::InteractionParams;
+
+pub struct InteractionOutcome {
+    pub success: bool,
+    pub summary: String,
+}
+
+/// Applique les effets d'une action sur les deux participants et
+/// l'inscrit dans leur historique.
+pub fn resolve(
+    action: &Action,
+    initiator: &mut Estajo,
+    target: &mut Estajo,
+    params: &InteractionParams,
+    rng: &mut impl Rng,
+) -> InteractionOutcome {
+    let (success, summary) = match action {
+        Action::Help => {
+            let amount = params.help_heal * initiator.genetics.factor(hooks::HELP_HEAL);
+            target.heal(amount);
+            initiator.needs.hunger = (initiator.needs.hunger - params.help_cost).max(0.0);
+
+            // L'aidant partage sa nourriture s'il est mieux nourri que la cible
+            let shared = params.help_food_share
+                .min((initiator.needs.hunger - target.needs.hunger) / 2.0)
+                .max(0.0);
+            if shared > 0.0 {
+                initiator.needs.hunger -= shared;
+                target.needs.eat(shared);
+                (true, format!("+{:.1} life, +{:.1} food", amount, shared))
+            } else {
+                (true, format!("+{:.1} life", amount))
+            }
+        }
+        Action::Hurt => {
+            let amount = params.hurt_damage * initiator.genetics.factor(hooks::HURT_DAMAGE);
+            target.take_damage(amount, DeathCause::Injury);
+            (true, format!("-{:.1} life", amount))
+        }
+        Action::Plot => {
+            let chance = plot_success_chance(initiator, target, params);
+            if rng.gen::<f32>() < chance {
+                target.take_damage(params.plot_damage, DeathCause::Injury);
+                target.needs.ambition = (target.needs.ambition - params.plot_ambition_shift).max(0.0);
+                initiator.needs.ambition = (initiator.needs.ambition - params.plot_ambition_shift).max(0.0);
+                (true, "success".to_string())
+            } else {
+                initiator.take_damage(params.plot_backlash, DeathCause::Injury);
+                (false, "failure".to_string())
+            }
+        }
+    };
+
+    initiator.add_event(Event::new(
+        EventType::Action(action.clone()),
+        format!("-> {} ({})", target.name, summary)
+    ));
+    target.add_event(Event::new(
+        EventType::Action(action.clone()),
+        format!("<- {} ({})", initiator.name, summary)
+    ));
+
+    InteractionOutcome { success, summary }
+}
+
+/// Chance de réussite d'un complot, pondérée par le rapport d'influence.
+pub fn plot_success_chance(initiator: &Estajo, target: &Estajo, params: &InteractionParams) -> f32 {
+    let ratio = initiator.genetics.factor(hooks::PLOT_SUCCESS) / target.genetics.divisor(hooks::PLOT_SUCCESS);
+    (params.plot_success_chance * ratio).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::test_support::pair;
+    use crate::models::traits::INFLUENCE;
+
+    #[test]
+    fn test_hurt_removes_life() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (mut a, mut b) = pair(&mut rng);
+        resolve(&Action::Hurt, &mut a, &mut b, &InteractionParams::default(), &mut rng);
+        assert!(b.life < 100.0);
+        assert_eq!(a.history.len(), 1);
+        assert_eq!(b.history.len(), 1);
+    }
+
+    #[test]
+    fn test_help_heals_target() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (mut a, mut b) = pair(&mut rng);
+        b.life = 50.0;
+        resolve(&Action::Help, &mut a, &mut b, &InteractionParams::default(), &mut rng);
+        assert!(b.life > 50.0);
+        assert!(a.needs.hunger < 100.0);
+    }
+
+    #[test]
+    fn test_help_shares_food() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (mut a, mut b) = pair(&mut rng);
+        b.needs.hunger = 20.0;
+        resolve(&Action::Help, &mut a, &mut b, &InteractionParams::default(), &mut rng);
+        assert!(b.needs.hunger > 20.0);
+
+        let params = InteractionParams {
+            help_food_share: 0.0,
+            ..Default::default()
+        };
+        b.needs.hunger = 20.0;
+        resolve(&Action::Help, &mut a, &mut b, &params, &mut rng);
+        assert_eq!(b.needs.hunger, 20.0);
+    }
+
+    #[test]
+    fn test_plot_success_scales_with_influence() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (mut a, b) = pair(&mut rng);
+        let params = InteractionParams::default();
+        let influence = b.genetics.value(INFLUENCE).unwrap();
+        a.genetics.set(INFLUENCE, influence);
+        let even = plot_success_chance(&a, &b, &params);
+        a.genetics.set(INFLUENCE, influence * 1.5);
+        assert!(plot_success_chance(&a, &b, &params) > even);
+
+        // Une cible sans influence est une proie facile, pas une division par zéro
+        let mut b = b;
+        b.genetics.set(INFLUENCE, 0.0);
+        assert_eq!(plot_success_chance(&a, &b, &params), 1.0);
+        a.genetics.set(INFLUENCE, 0.0);
+        assert_eq!(plot_success_chance(&a, &b, &params), 0.0);
+    }
+}
@@ -0,0 +1,95 @@
+// src/simulation/life.rs
+use crate::models::estajo::{Estajo, LifeStage};
+use crate::models::event::{DeathCause, Event, EventType, StateChange};
+use crate::models::traits::hooks;
+use super::parameters::LifeParams;
+
+/// Espérance de vie maximale, allongée par un bon facteur d'énergie.
+pub fn lifespan(estajo: &Estajo, params: &LifeParams) -> u32 {
+    (params.base_lifespan as f32 * estajo.genetics.factor(hooks::LIFESPAN)) as u32
+}
+
+pub fn stage(estajo: &Estajo, params: &LifeParams) -> LifeStage {
+    if estajo.age < params.adulthood_age {
+        LifeStage::Child
+    } else if estajo.age as f32 >= lifespan(estajo, params) as f32 * params.elder_ratio {
+        LifeStage::Elder
+    } else {
+        LifeStage::Adult
+    }
+}
+
+/// Vieillit l'estajo d'un tick. Retourne sa nouvelle étape de vie s'il
+/// vient d'en changer.
+pub fn grow(estajo: &mut Estajo, params: &LifeParams) -> Option<LifeStage> {
+    let before = stage(estajo, params);
+    estajo.age += 1;
+    let after = stage(estajo, params);
+
+    let damage = match after {
+        LifeStage::Elder => params.aging_damage * params.elder_aging_factor,
+        _ => params.aging_damage,
+    };
+    estajo.take_damage(damage, DeathCause::Aging);
+    if estajo.age >= lifespan(estajo, params) {
+        estajo.take_damage(estajo.life, DeathCause::Aging);
+    }
+
+    if before == after || !estajo.is_alive() {
+        return None;
+    }
+    estajo.add_event(Event::new(
+        EventType::StateChange(StateChange::LifeStage(after)),
+        format!("Became {}", after)
+    ));
+    Some(after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::test_support::estajo;
+
+    fn newborn() -> Estajo {
+        let mut estajo = estajo(0, &mut StdRng::seed_from_u64(0));
+        estajo.genetics.set(crate::models::traits::ENERGY, 1.0);
+        estajo
+    }
+
+    #[test]
+    fn test_stages() {
+        let params = LifeParams::default();
+        let mut estajo = newborn();
+        assert_eq!(stage(&estajo, &params), LifeStage::Child);
+        while estajo.age + 1 < params.adulthood_age {
+            assert_eq!(grow(&mut estajo, &params), None);
+        }
+        assert_eq!(grow(&mut estajo, &params), Some(LifeStage::Adult));
+        estajo.age = (params.base_lifespan as f32 * params.elder_ratio) as u32;
+        assert_eq!(stage(&estajo, &params), LifeStage::Elder);
+    }
+
+    #[test]
+    fn test_elders_age_faster() {
+        let params = LifeParams::default();
+        let mut adult = newborn();
+        adult.age = params.adulthood_age;
+        let mut elder = newborn();
+        elder.age = params.base_lifespan - 10;
+        grow(&mut adult, &params);
+        grow(&mut elder, &params);
+        assert!(elder.life < adult.life);
+    }
+
+    #[test]
+    fn test_death_at_lifespan() {
+        let params = LifeParams::default();
+        let mut estajo = newborn();
+        estajo.age = lifespan(&estajo, &params) - 1;
+        grow(&mut estajo, &params);
+        assert!(!estajo.is_alive());
+        assert_eq!(estajo.death_cause, Some(DeathCause::Aging));
+    }
+}
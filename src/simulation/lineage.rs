@@ -0,0 +1,218 @@
+// src/simulation/lineage.rs
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct LineageRecord {
+    pub parents: Vec<u32>,
+    pub children: Vec<u32>,
+    pub generation: u32,
+}
+
+/// Arbre généalogique de tous les estajoj ayant vécu, morts compris.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Lineage {
+    records: BTreeMap<u32, LineageRecord>,
+}
+
+impl Lineage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enregistre une naissance (ou un fondateur si `parents` est vide).
+    pub fn register(&mut self, id: u32, parents: &[u32]) {
+        let generation = parents.iter()
+            .filter_map(|p| self.records.get(p))
+            .map(|r| r.generation + 1)
+            .max()
+            .unwrap_or(0);
+        for parent in parents {
+            if let Some(record) = self.records.get_mut(parent) {
+                record.children.push(id);
+            }
+        }
+        self.records.insert(id, LineageRecord {
+            parents: parents.to_vec(),
+            children: Vec::new(),
+            generation,
+        });
+    }
+
+    pub fn get(&self, id: u32) -> Option<&LineageRecord> {
+        self.records.get(&id)
+    }
+
+    pub fn generation(&self, id: u32) -> u32 {
+        self.records.get(&id).map(|r| r.generation).unwrap_or(0)
+    }
+
+    pub fn parents(&self, id: u32) -> &[u32] {
+        self.records.get(&id).map(|r| r.parents.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn children(&self, id: u32) -> &[u32] {
+        self.records.get(&id).map(|r| r.children.as_slice()).unwrap_or(&[])
+    }
+
+    /// Distance (en générations) de `id` à chacun de ses ancêtres jusqu'à
+    /// `max_depth` générations, `id` lui-même compris à distance 0.
+    fn ancestor_distances(&self, id: u32, max_depth: u32) -> BTreeMap<u32, u32> {
+        let mut distances = BTreeMap::new();
+        let mut queue = VecDeque::from([(id, 0)]);
+        while let Some((current, depth)) = queue.pop_front() {
+            if distances.contains_key(&current) {
+                continue;
+            }
+            distances.insert(current, depth);
+            if depth < max_depth {
+                for &parent in self.parents(current) {
+                    queue.push_back((parent, depth + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    pub fn ancestors(&self, id: u32) -> BTreeSet<u32> {
+        let mut ancestors: BTreeSet<u32> = self.ancestor_distances(id, u32::MAX).into_keys().collect();
+        ancestors.remove(&id);
+        ancestors
+    }
+
+    pub fn descendants(&self, id: u32) -> BTreeSet<u32> {
+        let mut descendants = BTreeSet::new();
+        let mut stack = self.children(id).to_vec();
+        while let Some(current) = stack.pop() {
+            if descendants.insert(current) {
+                stack.extend_from_slice(self.children(current));
+            }
+        }
+        descendants
+    }
+
+    /// Estajoj partageant au moins un parent avec `id` (demi-frères et sœurs compris).
+    pub fn siblings(&self, id: u32) -> BTreeSet<u32> {
+        self.parents(id).iter()
+            .flat_map(|&p| self.children(p).iter().copied())
+            .filter(|&c| c != id)
+            .collect()
+    }
+
+    /// Ancêtre commun le plus proche de `a` et `b` (l'un peut être
+    /// l'ancêtre de l'autre).
+    pub fn common_ancestor(&self, a: u32, b: u32) -> Option<u32> {
+        let from_a = self.ancestor_distances(a, u32::MAX);
+        let from_b = self.ancestor_distances(b, u32::MAX);
+        from_a.iter()
+            .filter_map(|(id, da)| from_b.get(id).map(|db| (*id, da + db)))
+            .min_by_key(|&(id, distance)| (distance, id))
+            .map(|(id, _)| id)
+    }
+
+    /// Coefficient de consanguinité d'un enfant de `a` et `b` (méthode des
+    /// chemins de Wright, avec le plus court chemin par ancêtre et sans les
+    /// ancêtres déjà couverts par un ancêtre commun plus proche).
+    pub fn inbreeding_coefficient(&self, a: u32, b: u32) -> f32 {
+        self.coefficient(&self.ancestor_distances(a, u32::MAX), &self.ancestor_distances(b, u32::MAX))
+    }
+
+    /// Coefficients de consanguinité non nuls entre les `ids` pris deux à
+    /// deux, en ne remontant que `max_depth` générations. Chaque ascendance
+    /// n'est parcourue qu'une fois et seules les paires qui partagent un
+    /// ancêtre sont examinées. Les paires sont rangées `(plus petit, plus grand)`.
+    pub fn kinship(&self, ids: &[u32], max_depth: u32) -> BTreeMap<(u32, u32), f32> {
+        let ancestry: BTreeMap<u32, BTreeMap<u32, u32>> = ids.iter()
+            .map(|&id| (id, self.ancestor_distances(id, max_depth)))
+            .collect();
+        let mut relatives: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for (&id, distances) in &ancestry {
+            for &ancestor in distances.keys() {
+                relatives.entry(ancestor).or_default().push(id);
+            }
+        }
+        let mut kinship = BTreeMap::new();
+        for family in relatives.values() {
+            for (i, &a) in family.iter().enumerate() {
+                for &b in &family[i + 1..] {
+                    if let Entry::Vacant(entry) = kinship.entry((a, b)) {
+                        entry.insert(self.coefficient(&ancestry[&a], &ancestry[&b]));
+                    }
+                }
+            }
+        }
+        kinship.retain(|_, coefficient| *coefficient > 0.0);
+        kinship
+    }
+
+    fn coefficient(&self, from_a: &BTreeMap<u32, u32>, from_b: &BTreeMap<u32, u32>) -> f32 {
+        let common: BTreeMap<u32, u32> = from_a.iter()
+            .filter_map(|(id, da)| from_b.get(id).map(|db| (*id, da + db)))
+            .collect();
+        common.iter()
+            .filter(|(id, _)| !self.children(**id).iter().any(|c| common.contains_key(c)))
+            .map(|(_, steps)| 0.5f32.powi(*steps as i32 + 1))
+            .sum::<f32>()
+            .min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 + 1 -> 2, 3 ; 2 + 4 -> 5
+    fn family() -> Lineage {
+        let mut lineage = Lineage::new();
+        lineage.register(0, &[]);
+        lineage.register(1, &[]);
+        lineage.register(2, &[0, 1]);
+        lineage.register(3, &[0, 1]);
+        lineage.register(4, &[]);
+        lineage.register(5, &[2, 4]);
+        lineage
+    }
+
+    #[test]
+    fn test_generations_and_links() {
+        let lineage = family();
+        assert_eq!(lineage.generation(5), 2);
+        assert_eq!(lineage.children(0), &[2, 3]);
+        assert_eq!(lineage.ancestors(5), BTreeSet::from([0, 1, 2, 4]));
+        assert_eq!(lineage.descendants(0), BTreeSet::from([2, 3, 5]));
+        assert_eq!(lineage.siblings(2), BTreeSet::from([3]));
+    }
+
+    #[test]
+    fn test_common_ancestor() {
+        let lineage = family();
+        assert_eq!(lineage.common_ancestor(3, 5), Some(0));
+        assert_eq!(lineage.common_ancestor(2, 5), Some(2));
+        assert_eq!(lineage.common_ancestor(1, 4), None);
+    }
+
+    #[test]
+    fn test_inbreeding_coefficient() {
+        let lineage = family();
+        assert_eq!(lineage.inbreeding_coefficient(0, 4), 0.0);
+        assert_eq!(lineage.inbreeding_coefficient(2, 3), 0.25);
+        assert_eq!(lineage.inbreeding_coefficient(3, 5), 0.125);
+        assert_eq!(lineage.inbreeding_coefficient(2, 5), 0.25);
+    }
+
+    #[test]
+    fn test_kinship_of_a_population() {
+        let lineage = family();
+        let kinship = lineage.kinship(&[0, 2, 3, 4, 5], u32::MAX);
+        for (&(a, b), &coefficient) in &kinship {
+            assert_eq!(coefficient, lineage.inbreeding_coefficient(a, b));
+        }
+        assert_eq!(kinship.get(&(2, 3)), Some(&0.25));
+        assert_eq!(kinship.get(&(0, 4)), None);
+
+        // Sans remonter plus loin que les parents, 3 et 5 ne sont plus parents
+        let close = lineage.kinship(&[3, 5], 1);
+        assert!(close.is_empty());
+    }
+}
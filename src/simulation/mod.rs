@@ -1,6 +1,22 @@
 // src/simulation/mod.rs
 pub mod world;
 pub mod parameters;
+pub mod interaction;
+pub mod ambition;
+pub mod catastrophe;
+pub mod disease;
+pub mod environment;
+pub mod food;
+pub mod groups;
+pub mod hierarchy;
+pub mod life;
+pub mod lineage;
+pub mod outcome;
+pub mod pipeline;
+pub mod relationships;
+pub mod space;
+pub mod stats;
+pub mod termination;
 
 // src/simulation/parameters.rs
 pub struct SimulationParams {
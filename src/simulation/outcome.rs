@@ -0,0 +1,48 @@
+// src/simulation/outcome.rs
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::models::event::Event;
+
+/// Raison pour laquelle une simulation s'est arrêtée.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EndCondition {
+    /// Plus aucun estajo vivant
+    Extinction,
+    /// Durée prévue atteinte
+    MaxTicks,
+    /// La population a atteint son plafond
+    PopulationCap,
+    /// Il ne reste que des mâles ou que des femelles
+    SingleSex,
+    /// Tous les allèles de ce trait sont devenus identiques
+    TraitFixation(String),
+    /// Le temps de calcul alloué est écoulé
+    TimeBudget,
+}
+
+impl fmt::Display for EndCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndCondition::Extinction => write!(f, "extinction"),
+            EndCondition::MaxTicks => write!(f, "duration reached"),
+            EndCondition::PopulationCap => write!(f, "population cap reached"),
+            EndCondition::SingleSex => write!(f, "a single sex remains"),
+            EndCondition::TraitFixation(name) => write!(f, "{} is fixed", name),
+            EndCondition::TimeBudget => write!(f, "time budget exhausted"),
+        }
+    }
+}
+
+/// Résultat d'un tick : ce qui s'est passé et, le cas échéant, la fin de
+/// la simulation.
+#[derive(Debug)]
+pub struct TickOutcome {
+    pub events: Vec<Event>,
+    pub end: Option<EndCondition>,
+}
+
+impl TickOutcome {
+    pub fn is_over(&self) -> bool {
+        self.end.is_some()
+    }
+}
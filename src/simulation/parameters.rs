@@ -1,26 +1,888 @@
 // src/simulation/parameters.rs
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as _;
+use serde_json::{Map, Value};
+use crate::behaviors::utility::Curve;
+use crate::error::{Error, Result};
+use crate::models::traits::{Inheritance, TraitDistribution, TraitRegistry};
+use super::catastrophe::{RandomCatastrophe, ScheduledCatastrophe};
+use super::disease::Disease;
+use super::environment::Modifiers;
+use super::termination::StopCondition;
 
 
+// Dérivé sur `Self` pour pouvoir migrer les anciens fichiers avant la désérialisation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct SimulationParams {
-    pub interaction_chance: f32,
-    pub reproduction_chance: f32,
-    pub hunger_tick_chance: f32,    
-    pub ambition_tick_chance: f32,  
     pub simulation_duration: u32,
     pub initial_population: u32,
+    /// Graine du générateur aléatoire. `None` en tire une au hasard,
+    /// qui est ensuite enregistrée pour pouvoir rejouer la simulation.
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub space: SpaceParams,
+    #[serde(default)]
+    pub life: LifeParams,
+    #[serde(default)]
+    pub mating: MatingParams,
+    #[serde(default)]
+    pub gestation: GestationParams,
+    #[serde(default)]
+    pub genetics: GeneticsParams,
+    #[serde(default)]
+    pub lineage: LineageParams,
+    #[serde(default)]
+    pub needs: NeedsParams,
+    #[serde(default)]
+    pub food: FoodParams,
+    #[serde(default)]
+    pub stats: StatsParams,
+    #[serde(default)]
+    pub interactions: InteractionParams,
+    #[serde(default)]
+    pub ambition: AmbitionParams,
+    #[serde(default)]
+    pub utility: UtilityParams,
+    #[serde(default)]
+    pub random: RandomParams,
+    #[serde(default)]
+    pub hierarchy: HierarchyParams,
+    #[serde(default)]
+    pub groups: GroupParams,
+    #[serde(default)]
+    pub relationships: RelationshipParams,
+    #[serde(default)]
+    pub disease: DiseaseParams,
+    #[serde(default)]
+    pub environment: EnvironmentParams,
+    #[serde(default)]
+    pub catastrophes: CatastropheParams,
+    #[serde(default)]
+    pub termination: TerminationParams,
 }
 
 impl Default for SimulationParams {
     fn default() -> Self {
         Self {
-            interaction_chance: 0.35,
-            reproduction_chance: 0.2,
-            hunger_tick_chance: 0.2, 
-            ambition_tick_chance: 0.2,
             simulation_duration: 100,
             initial_population: 10,
+            seed: None,
+            space: SpaceParams::default(),
+            life: LifeParams::default(),
+            mating: MatingParams::default(),
+            gestation: GestationParams::default(),
+            genetics: GeneticsParams::default(),
+            lineage: LineageParams::default(),
+            needs: NeedsParams::default(),
+            food: FoodParams::default(),
+            stats: StatsParams::default(),
+            interactions: InteractionParams::default(),
+            ambition: AmbitionParams::default(),
+            utility: UtilityParams::default(),
+            random: RandomParams::default(),
+            hierarchy: HierarchyParams::default(),
+            groups: GroupParams::default(),
+            relationships: RelationshipParams::default(),
+            disease: DiseaseParams::default(),
+            environment: EnvironmentParams::default(),
+            catastrophes: CatastropheParams::default(),
+            termination: TerminationParams::default(),
+        }
+    }
+}
+
+impl Serialize for SimulationParams {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        Self::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimulationParams {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let mut value = Value::deserialize(deserializer)?;
+        if let Value::Object(fields) = &mut value {
+            migrate_random_chances(fields);
+        }
+        Self::deserialize(value).map_err(D::Error::custom)
+    }
+}
+
+/// Les chances du comportement aléatoire étaient autrefois à la racine des
+/// paramètres : on les range dans `random`, sans écraser celles qui y sont déjà.
+fn migrate_random_chances(fields: &mut Map<String, Value>) {
+    const LEGACY: [(&str, &str); 4] = [
+        ("interaction_chance", "interaction_chance"),
+        ("reproduction_chance", "reproduction_chance"),
+        ("hunger_tick_chance", "hunger_chance"),
+        ("ambition_tick_chance", "ambition_chance"),
+    ];
+    let legacy: Vec<(&str, Value)> = LEGACY.iter()
+        .filter_map(|&(old, new)| fields.remove(old).map(|value| (new, value)))
+        .collect();
+    if legacy.is_empty() {
+        return;
+    }
+    let Value::Object(random) = fields.entry("random").or_insert_with(|| Value::Object(Map::new())) else {
+        return;
+    };
+    for (name, value) in legacy {
+        random.entry(name).or_insert(value);
+    }
+    // Les anciens fichiers ne portaient pas forcément les quatre chances
+    if let Ok(Value::Object(defaults)) = serde_json::to_value(RandomParams::default()) {
+        for (name, value) in defaults {
+            random.entry(name).or_insert(value);
+        }
+    }
+}
+
+impl SimulationParams {
+    /// Vérifie la cohérence des paramètres avant de créer un monde.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: String| Err(Error::InvalidParameters(reason));
+        if self.space.width == 0 || self.space.height == 0 {
+            return invalid(format!("the world is {}x{}", self.space.width, self.space.height));
+        }
+        let chances = [
+            ("life.elder_ratio", self.life.elder_ratio),
+            ("mating.conception_chance", self.mating.conception_chance),
+            ("gestation.miscarriage_chance", self.gestation.miscarriage_chance),
+            ("genetics.mutation_rate", self.genetics.mutation_rate),
+            ("genetics.recombination_rate", self.genetics.recombination_rate),
+            ("food.regrowth_rate", self.food.regrowth_rate),
+            ("interactions.plot_success_chance", self.interactions.plot_success_chance),
+            ("random.interaction_chance", self.random.interaction_chance),
+            ("random.reproduction_chance", self.random.reproduction_chance),
+            ("random.hunger_chance", self.random.hunger_chance),
+            ("random.ambition_chance", self.random.ambition_chance),
+            ("hierarchy.obedience", self.hierarchy.obedience),
+            ("relationships.decay", self.relationships.decay),
+        ];
+        for (name, value) in chances {
+            check_chance(name, value)?;
+        }
+        let amounts = [
+            ("space.interaction_radius", self.space.interaction_radius),
+            ("life.aging_damage", self.life.aging_damage),
+            ("life.elder_aging_factor", self.life.elder_aging_factor),
+            ("mating.drive_rate", self.mating.drive_rate),
+            ("mating.min_drive", self.mating.min_drive),
+            ("mating.fitness_weight", self.mating.fitness_weight),
+            ("gestation.food_cost", self.gestation.food_cost),
+            ("gestation.miscarriage_hunger", self.gestation.miscarriage_hunger),
+            ("genetics.mutation_magnitude", self.genetics.mutation_magnitude),
+            ("lineage.inbreeding_penalty", self.lineage.inbreeding_penalty),
+            ("needs.hunger_drain", self.needs.hunger_drain),
+            ("needs.starvation_threshold", self.needs.starvation_threshold),
+            ("needs.starvation_damage", self.needs.starvation_damage),
+            ("food.initial_stock", self.food.initial_stock),
+            ("food.capacity", self.food.capacity),
+            ("food.meal_size", self.food.meal_size),
+            ("food.hunger_threshold", self.food.hunger_threshold),
+            ("stats.energy_recovery", self.stats.energy_recovery),
+            ("stats.action_energy_cost", self.stats.action_energy_cost),
+            ("stats.rest_energy", self.stats.rest_energy),
+            ("stats.satisfaction_rate", self.stats.satisfaction_rate),
+            ("stats.influence_decay", self.stats.influence_decay),
+            ("stats.help_influence", self.stats.help_influence),
+            ("stats.help_satisfaction", self.stats.help_satisfaction),
+            ("stats.hurt_satisfaction", self.stats.hurt_satisfaction),
+            ("stats.plot_influence", self.stats.plot_influence),
+            ("stats.low_threshold", self.stats.low_threshold),
+            ("stats.high_threshold", self.stats.high_threshold),
+            ("interactions.help_heal", self.interactions.help_heal),
+            ("interactions.help_cost", self.interactions.help_cost),
+            ("interactions.help_food_share", self.interactions.help_food_share),
+            ("interactions.hurt_damage", self.interactions.hurt_damage),
+            ("interactions.plot_damage", self.interactions.plot_damage),
+            ("interactions.plot_ambition_shift", self.interactions.plot_ambition_shift),
+            ("interactions.plot_backlash", self.interactions.plot_backlash),
+            ("ambition.threshold", self.ambition.threshold),
+            ("ambition.challenge_weight", self.ambition.challenge_weight),
+            ("ambition.plot_weight", self.ambition.plot_weight),
+            ("ambition.hoard_weight", self.ambition.hoard_weight),
+            ("ambition.challenge_stake", self.ambition.challenge_stake),
+            ("ambition.hoard_amount", self.ambition.hoard_amount),
+            ("ambition.relief", self.ambition.relief),
+            ("ambition.frustration", self.ambition.frustration),
+            ("utility.sharpness", self.utility.sharpness),
+            ("utility.ally_bonus", self.utility.ally_bonus),
+            ("utility.rival_hostility", self.utility.rival_hostility),
+            ("hierarchy.popularity_weight", self.hierarchy.popularity_weight),
+            ("hierarchy.leader_status", self.hierarchy.leader_status),
+            ("hierarchy.demotion_status", self.hierarchy.demotion_status),
+            ("hierarchy.follow_radius", self.hierarchy.follow_radius),
+            ("groups.kinship_bond", self.groups.kinship_bond),
+            ("groups.share_amount", self.groups.share_amount),
+            ("groups.donor_hunger", self.groups.donor_hunger),
+            ("relationships.help_affinity", self.relationships.help_affinity),
+            ("relationships.hurt_affinity", self.relationships.hurt_affinity),
+            ("relationships.hurt_grudge", self.relationships.hurt_grudge),
+            ("relationships.plot_smear", self.relationships.plot_smear),
+            ("relationships.plot_grudge", self.relationships.plot_grudge),
+            ("relationships.challenge_grudge", self.relationships.challenge_grudge),
+            ("relationships.hoard_resentment", self.relationships.hoard_resentment),
+            ("relationships.bias", self.relationships.bias),
+        ];
+        for (name, value) in amounts {
+            check_amount(name, value)?;
+        }
+        let cycles = [
+            ("spring", &self.environment.spring),
+            ("summer", &self.environment.summer),
+            ("autumn", &self.environment.autumn),
+            ("winter", &self.environment.winter),
+            ("day", &self.environment.day),
+            ("night", &self.environment.night),
+        ];
+        for (cycle, m) in cycles {
+            for (name, value) in [("regrowth", m.regrowth), ("hunger", m.hunger), ("fertility", m.fertility), ("mortality", m.mortality)] {
+                check_amount(&format!("environment.{}.{}", cycle, name), value)?;
+            }
+        }
+        // Des bornes inversées ou indéfinies feraient paniquer le tirage des allèles
+        for def in self.genetics.traits.iter() {
+            if !def.min.is_finite() || !def.max.is_finite() || def.min > def.max {
+                return invalid(format!("trait {} needs finite bounds with min <= max, got {}..{}", def.name, def.min, def.max));
+            }
+            let distribution_is_valid = match def.distribution {
+                TraitDistribution::Uniform { low, high } => low.is_finite() && high.is_finite() && low <= high,
+                TraitDistribution::Constant { value } => value.is_finite(),
+            };
+            if !distribution_is_valid {
+                return invalid(format!("trait {} has an invalid distribution {:?}", def.name, def.distribution));
+            }
+            if let Inheritance::Mendelian { dominance } = def.inheritance {
+                check_chance(&format!("trait {} dominance", def.name), dominance)?;
+            }
+        }
+        for condition in &self.termination.conditions {
+            match condition {
+                StopCondition::TimeBudget { seconds } if !seconds.is_finite() || *seconds < 0.0 => {
+                    return invalid(format!("time_budget seconds must be a non-negative number, got {}", seconds));
+                }
+                StopCondition::TraitFixation { tolerance, .. } => {
+                    check_amount("trait_fixation tolerance", *tolerance)?;
+                }
+                _ => {}
+            }
+        }
+        // Sans borne, une population figée ferait tourner la simulation sans fin
+        if !self.termination.conditions.iter().any(StopCondition::is_bounded) {
+            return invalid("stop conditions need max_ticks or time_budget to end the run".to_string());
+        }
+        // Un seuil nul lierait entre eux des estajoj qui ne se connaissent pas
+        if self.groups.bond_threshold <= 0.0 {
+            return invalid("groups.bond_threshold must be positive".to_string());
+        }
+        if self.hierarchy.demotion_status > self.hierarchy.leader_status {
+            return invalid("hierarchy.demotion_status exceeds hierarchy.leader_status".to_string());
+        }
+        for (i, disease) in self.disease.diseases.iter().enumerate() {
+            if disease.name.is_empty() || self.disease.diseases[..i].iter().any(|d| d.name == disease.name) {
+                return invalid(format!("disease names must be unique and non-empty, got {:?}", disease.name));
+            }
+            check_chance(&format!("{} contact_transmission", disease.name), disease.contact_transmission)?;
+            check_chance(&format!("{} proximity_transmission", disease.name), disease.proximity_transmission)?;
+            check_chance(&format!("{} outbreak_chance", disease.name), disease.outbreak_chance)?;
+            check_amount(&format!("{} damage", disease.name), disease.damage)?;
+        }
+        for random in &self.catastrophes.random {
+            check_chance(&format!("{} chance", random.catastrophe.kind), random.chance)?;
+        }
+        let catastrophes = self.catastrophes.scheduled.iter().map(|s| &s.catastrophe)
+            .chain(self.catastrophes.random.iter().map(|r| &r.catastrophe));
+        for catastrophe in catastrophes {
+            check_chance(&format!("{} severity", catastrophe.kind), catastrophe.severity)?;
+            check_amount(&format!("{} damage", catastrophe.kind), catastrophe.damage)?;
+            if let Some(radius) = catastrophe.radius {
+                check_amount(&format!("{} radius", catastrophe.kind), radius)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_chance(name: &str, value: f32) -> Result<()> {
+    if !(0.0..=1.0).contains(&value) {
+        return Err(Error::InvalidParameters(format!("{} must be between 0 and 1, got {}", name, value)));
+    }
+    Ok(())
+}
+
+/// Quantités, durées et multiplicateurs : une valeur négative ou infinie
+/// inverserait leur effet ou ferait diverger la simulation.
+fn check_amount(name: &str, value: f32) -> Result<()> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(Error::InvalidParameters(format!("{} must be a non-negative number, got {}", name, value)));
+    }
+    Ok(())
+}
+
+/// Géographie du monde.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpaceParams {
+    pub width: u32,
+    pub height: u32,
+    /// Distance maximale pour interagir, s'accoupler ou se nourrir
+    pub interaction_radius: f32,
+    /// Nombre de cases parcourues au plus par tick
+    pub move_speed: u32,
+}
+
+impl Default for SpaceParams {
+    fn default() -> Self {
+        Self {
+            width: 10,
+            height: 10,
+            interaction_radius: 2.0,
+            move_speed: 1,
+        }
+    }
+}
+
+/// Étapes de la vie : enfance, âge adulte, vieillesse.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifeParams {
+    /// Âge (en ticks) auquel un enfant devient adulte
+    pub adulthood_age: u32,
+    /// Espérance de vie maximale à facteur d'énergie 1
+    pub base_lifespan: u32,
+    /// Part de l'espérance de vie à partir de laquelle l'estajo est âgé
+    pub elder_ratio: f32,
+    /// Vie perdue par tick en vieillissant
+    pub aging_damage: f32,
+    /// Multiplicateur du vieillissement pour les âgés
+    pub elder_aging_factor: f32,
+}
+
+impl Default for LifeParams {
+    fn default() -> Self {
+        Self {
+            adulthood_age: 40,
+            base_lifespan: 400,
+            elder_ratio: 0.75,
+            aging_damage: 0.1,
+            elder_aging_factor: 5.0,
+        }
+    }
+}
+
+/// Désir de se reproduire et choix du partenaire.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatingParams {
+    /// Désir gagné par tick par un adulte fertile rassasié
+    pub drive_rate: f32,
+    /// Désir minimal pour chercher un partenaire
+    pub min_drive: f32,
+    /// Exposant appliqué à l'aptitude génétique du partenaire (0 = indifférent)
+    pub fitness_weight: f32,
+    /// Chance qu'un accouplement aboutisse à une conception, multipliée par
+    /// la fertilité génétique des partenaires et par la saison
+    pub conception_chance: f32,
+}
+
+impl Default for MatingParams {
+    fn default() -> Self {
+        Self {
+            drive_rate: 4.0,
+            min_drive: 20.0,
+            fitness_weight: 2.0,
+            conception_chance: 0.8,
+        }
+    }
+}
+
+/// Grossesse et naissances.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GestationParams {
+    /// Durée de la grossesse en ticks
+    pub duration: u32,
+    /// Nourriture supplémentaire consommée par tick par la mère
+    pub food_cost: f32,
+    /// Niveau de nourriture sous lequel la grossesse est menacée
+    pub miscarriage_hunger: f32,
+    /// Chance de fausse couche par tick lorsque la mère est affamée
+    pub miscarriage_chance: f32,
+    /// Ticks de repos après une naissance ou une fausse couche
+    pub cooldown: u32,
+}
+
+impl Default for GestationParams {
+    fn default() -> Self {
+        Self {
+            duration: 20,
+            food_cost: 1.0,
+            miscarriage_hunger: 10.0,
+            miscarriage_chance: 0.1,
+            cooldown: 30,
+        }
+    }
+}
+
+/// Transmission des gènes diploïdes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneticsParams {
+    /// Chance qu'un allèle transmis mute
+    pub mutation_rate: f32,
+    /// Écart maximal ajouté à un allèle muté
+    pub mutation_magnitude: f32,
+    /// Chance de changer de brin entre deux loci voisins (0.5 = loci indépendants)
+    pub recombination_rate: f32,
+    /// Traits héritables et leurs règles
+    #[serde(default)]
+    pub traits: TraitRegistry,
+}
+
+impl Default for GeneticsParams {
+    fn default() -> Self {
+        Self {
+            mutation_rate: 0.05,
+            mutation_magnitude: 0.1,
+            recombination_rate: 0.5,
+            traits: TraitRegistry::default(),
+        }
+    }
+}
+
+/// Filiation et consanguinité.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineageParams {
+    /// Perte de facteurs génétiques par unité de consanguinité (0 = aucune)
+    pub inbreeding_penalty: f32,
+}
+
+impl Default for LineageParams {
+    fn default() -> Self {
+        Self {
+            inbreeding_penalty: 0.5,
+        }
+    }
+}
+
+/// Conséquences des besoins non satisfaits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeedsParams {
+    /// Faim perdue par tick, modulée par l'environnement
+    pub hunger_drain: f32,
+    /// Niveau de nourriture sous lequel l'estajo commence à dépérir
+    pub starvation_threshold: f32,
+    /// Vie perdue par tick à 0% de nourriture, divisée par le facteur d'énergie
+    pub starvation_damage: f32,
+}
+
+impl Default for NeedsParams {
+    fn default() -> Self {
+        Self {
+            hunger_drain: 2.0,
+            starvation_threshold: 10.0,
+            starvation_damage: 2.0,
+        }
+    }
+}
+
+/// Ressource alimentaire du monde.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FoodParams {
+    /// Nourriture initiale par case
+    pub initial_stock: f32,
+    /// Capacité de charge par case : la réserve ne dépasse jamais cette quantité
+    pub capacity: f32,
+    /// Fraction du manque à la capacité qui repousse à chaque tick
+    pub regrowth_rate: f32,
+    /// Quantité mangée par repas
+    pub meal_size: f32,
+    /// Niveau de nourriture sous lequel un estajo cherche à manger
+    pub hunger_threshold: f32,
+}
+
+impl Default for FoodParams {
+    fn default() -> Self {
+        Self {
+            initial_stock: 3.0,
+            capacity: 5.0,
+            regrowth_rate: 0.05,
+            meal_size: 30.0,
+            hunger_threshold: 30.0,
+        }
+    }
+}
+
+/// Énergie, satisfaction et influence de chaque estajo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsParams {
+    /// Énergie gagnée (ou perdue) par tick à satiété (ou affamé)
+    pub energy_recovery: f32,
+    /// Énergie dépensée par l'auteur d'une action
+    pub action_energy_cost: f32,
+    /// Énergie regagnée par un estajo qui se repose
+    pub rest_energy: f32,
+    /// Vitesse à laquelle la satisfaction suit les besoins
+    pub satisfaction_rate: f32,
+    /// Part de l'influence perdue à chaque tick
+    pub influence_decay: f32,
+    pub help_influence: f32,
+    pub help_satisfaction: f32,
+    pub hurt_satisfaction: f32,
+    /// Influence prise à la victime d'un complot réussi
+    pub plot_influence: f32,
+    /// Seuils sous et au-dessus desquels un changement est signalé
+    pub low_threshold: f32,
+    pub high_threshold: f32,
+}
+
+impl Default for StatsParams {
+    fn default() -> Self {
+        Self {
+            energy_recovery: 2.0,
+            action_energy_cost: 5.0,
+            rest_energy: 5.0,
+            satisfaction_rate: 1.0,
+            influence_decay: 0.02,
+            help_influence: 3.0,
+            help_satisfaction: 5.0,
+            hurt_satisfaction: 10.0,
+            plot_influence: 10.0,
+            low_threshold: 25.0,
+            high_threshold: 75.0,
+        }
+    }
+}
+
+/// Effets des actions Aider / Blesser / Comploter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionParams {
+    /// Vie rendue à la cible, multipliée par le facteur d'énergie de l'aidant
+    pub help_heal: f32,
+    /// Nourriture dépensée par l'aidant
+    pub help_cost: f32,
+    /// Nourriture partagée par l'aidant avec la cible (0 = pas de partage)
+    pub help_food_share: f32,
+    /// Vie retirée à la cible, multipliée par le facteur d'énergie de l'agresseur
+    pub hurt_damage: f32,
+    /// Chance de réussite d'un complot à influence égale
+    pub plot_success_chance: f32,
+    /// Vie retirée à la victime d'un complot réussi
+    pub plot_damage: f32,
+    /// Ambition perdue par la victime et assouvie chez le comploteur
+    pub plot_ambition_shift: f32,
+    /// Vie perdue par le comploteur en cas d'échec
+    pub plot_backlash: f32,
+}
+
+impl Default for InteractionParams {
+    fn default() -> Self {
+        Self {
+            help_heal: 5.0,
+            help_cost: 5.0,
+            help_food_share: 10.0,
+            hurt_damage: 10.0,
+            plot_success_chance: 0.5,
+            plot_damage: 3.0,
+            plot_ambition_shift: 15.0,
+            plot_backlash: 5.0,
+        }
+    }
+}
+
+/// Ce que font les estajoj ambitieux et ce qu'il en coûte.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmbitionParams {
+    /// Ambition à partir de laquelle un estajo passe à l'acte
+    pub threshold: f32,
+    /// Poids relatif du défi pour le rang
+    pub challenge_weight: f32,
+    /// Poids relatif du complot contre un rival
+    pub plot_weight: f32,
+    /// Poids relatif de l'accaparement de nourriture
+    pub hoard_weight: f32,
+    /// Influence que le vainqueur d'un défi prend au perdant
+    pub challenge_stake: f32,
+    /// Nourriture accaparée à influence moyenne
+    pub hoard_amount: f32,
+    /// Ambition apaisée par un succès
+    pub relief: f32,
+    /// Satisfaction perdue par un échec
+    pub frustration: f32,
+}
+
+impl Default for AmbitionParams {
+    fn default() -> Self {
+        Self {
+            threshold: 70.0,
+            challenge_weight: 1.0,
+            plot_weight: 1.0,
+            hoard_weight: 1.0,
+            challenge_stake: 10.0,
+            hoard_amount: 20.0,
+            relief: 20.0,
+            frustration: 10.0,
+        }
+    }
+}
+
+/// Courbes de score des décisions de chaque estajo. Chaque courbe reçoit
+/// une entrée entre 0 et 1 : manque de nourriture pour manger, affinité et
+/// détresse du voisin pour aider, rancune pour blesser, méfiance pour
+/// comploter, désir pour s'accoupler, ambition pour intriguer et fatigue
+/// pour se reposer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UtilityParams {
+    pub eat: Curve,
+    pub help: Curve,
+    pub hurt: Curve,
+    pub plot: Curve,
+    pub mate: Curve,
+    pub scheme: Curve,
+    pub rest: Curve,
+    /// Exposant appliqué aux scores : 0 = choix au hasard, grand = toujours le meilleur
+    pub sharpness: f32,
+    /// Ajouté à l'entrée de l'aide envers un membre de son groupe
+    pub ally_bonus: f32,
+    /// Ajouté aux entrées de l'agression et du complot envers un membre d'un autre groupe
+    pub rival_hostility: f32,
+}
+
+impl Default for UtilityParams {
+    fn default() -> Self {
+        Self {
+            eat: Curve::Logistic { midpoint: 0.7, steepness: 10.0 },
+            help: Curve::Linear { slope: 0.6, offset: 0.1 },
+            hurt: Curve::Linear { slope: 0.8, offset: 0.05 },
+            plot: Curve::Linear { slope: 0.5, offset: 0.05 },
+            mate: Curve::Linear { slope: 0.6, offset: 0.0 },
+            scheme: Curve::Logistic { midpoint: 0.75, steepness: 12.0 },
+            rest: Curve::Linear { slope: 0.5, offset: 0.3 },
+            sharpness: 3.0,
+            ally_bonus: 0.15,
+            rival_hostility: 0.2,
+        }
+    }
+}
+
+/// Chances par tick du comportement `random` (voir `RandomBehavior`). Les
+/// chances d'interaction, d'accouplement et d'ambition sont partagées entre
+/// tous les estajoj vivants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandomParams {
+    pub interaction_chance: f32,
+    pub reproduction_chance: f32,
+    /// Chance qu'un estajo affamé cherche à manger
+    pub hunger_chance: f32,
+    pub ambition_chance: f32,
+}
+
+impl Default for RandomParams {
+    fn default() -> Self {
+        Self {
+            interaction_chance: 0.35,
+            reproduction_chance: 0.2,
+            hunger_chance: 0.2,
+            ambition_chance: 0.2,
+        }
+    }
+}
+
+/// Statut, chefs et suiveurs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HierarchyParams {
+    /// Part de la popularité ajoutée à l'influence pour former le statut
+    pub popularity_weight: f32,
+    /// Statut à partir duquel un estajo devient un chef
+    pub leader_status: f32,
+    /// Statut sous lequel un chef perd sa place
+    pub demotion_status: f32,
+    /// Distance maximale entre un suiveur et son chef
+    pub follow_radius: f32,
+    /// Chance qu'un suiveur imite l'action de son chef envers un voisin
+    pub obedience: f32,
+}
+
+impl Default for HierarchyParams {
+    fn default() -> Self {
+        Self {
+            popularity_weight: 0.1,
+            leader_status: 35.0,
+            demotion_status: 20.0,
+            follow_radius: 4.0,
+            obedience: 0.5,
+        }
+    }
+}
+
+/// Formation des groupes et solidarité entre leurs membres.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupParams {
+    /// Lien minimal (affinité mutuelle plus parenté) pour appartenir au même groupe
+    pub bond_threshold: f32,
+    /// Lien apporté par une parenté proche (parent, enfant, frère ou sœur)
+    pub kinship_bond: f32,
+    /// Taille minimale d'un groupe
+    pub min_size: usize,
+    /// Nourriture qu'un membre rassasié cède à un membre affamé à chaque tick
+    pub share_amount: f32,
+    /// Faim au-dessus de laquelle un membre partage sa nourriture
+    pub donor_hunger: f32,
+}
+
+impl Default for GroupParams {
+    fn default() -> Self {
+        Self {
+            bond_threshold: 25.0,
+            kinship_bond: 40.0,
+            min_size: 2,
+            share_amount: 10.0,
+            donor_hunger: 60.0,
+        }
+    }
+}
+
+/// Évolution des liens entre estajoj.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipParams {
+    /// Affinité (et confiance) gagnée par l'aidé envers l'aidant
+    pub help_affinity: f32,
+    /// Affinité et confiance perdues par la victime envers l'agresseur
+    pub hurt_affinity: f32,
+    /// Rancune de la victime envers l'agresseur
+    pub hurt_grudge: f32,
+    /// Affinité perdue par les tiers envers la victime d'un complot réussi
+    pub plot_smear: f32,
+    /// Rancune de la cible envers l'auteur d'un complot découvert
+    pub plot_grudge: f32,
+    /// Rancune du perdant d'un défi envers le vainqueur
+    pub challenge_grudge: f32,
+    /// Affinité perdue par les voisins envers celui qui accapare la nourriture
+    pub hoard_resentment: f32,
+    /// Part des sentiments qui s'estompe à chaque tick
+    pub decay: f32,
+    /// Poids des sentiments dans le choix des cibles et partenaires (0 = aucun)
+    pub bias: f32,
+}
+
+impl Default for RelationshipParams {
+    fn default() -> Self {
+        Self {
+            help_affinity: 10.0,
+            hurt_affinity: 10.0,
+            hurt_grudge: 15.0,
+            plot_smear: 5.0,
+            plot_grudge: 20.0,
+            challenge_grudge: 10.0,
+            hoard_resentment: 5.0,
+            decay: 0.01,
+            bias: 1.0,
+        }
+    }
+}
+
+/// Maladies en circulation, aucune par défaut (voir `Disease::fever`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiseaseParams {
+    /// Maladies transmissibles, chacune avec ses règles de contagion
+    pub diseases: Vec<Disease>,
+}
+
+/// Saisons et alternance du jour et de la nuit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentParams {
+    /// Durée d'une saison en ticks (0 = pas de saisons)
+    pub season_length: u32,
+    /// Durée d'un jour complet en ticks, la nuit en occupant la seconde moitié (0 = jour permanent)
+    pub day_length: u32,
+    pub spring: Modifiers,
+    pub summer: Modifiers,
+    pub autumn: Modifiers,
+    pub winter: Modifiers,
+    pub day: Modifiers,
+    pub night: Modifiers,
+}
+
+impl Default for EnvironmentParams {
+    fn default() -> Self {
+        Self {
+            season_length: 50,
+            day_length: 10,
+            spring: Modifiers::new(1.3, 1.0, 1.2, 0.95),
+            summer: Modifiers::new(1.2, 1.0, 1.0, 1.0),
+            autumn: Modifiers::new(0.9, 1.0, 0.9, 1.0),
+            winter: Modifiers::new(0.6, 1.15, 0.7, 1.1),
+            day: Modifiers::new(1.2, 1.1, 1.0, 1.0),
+            night: Modifiers::new(0.8, 0.9, 1.0, 1.0),
         }
     }
-}
\ No newline at end of file
+}
+
+/// Chocs infligés à la population. Aucun par défaut.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CatastropheParams {
+    /// Scénario : catastrophes déclenchées à des ticks précis
+    #[serde(default)]
+    pub scheduled: Vec<ScheduledCatastrophe>,
+    /// Catastrophes qui surviennent au hasard, chacune avec sa fréquence
+    #[serde(default)]
+    pub random: Vec<RandomCatastrophe>,
+}
+
+/// Conditions d'arrêt de la simulation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminationParams {
+    /// La simulation s'arrête à la première condition remplie
+    pub conditions: Vec<StopCondition>,
+}
+
+impl Default for TerminationParams {
+    fn default() -> Self {
+        Self {
+            conditions: vec![StopCondition::Extinction, StopCondition::MaxTicks],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_random_chances_are_migrated() {
+        let json = r#"{
+            "interaction_chance": 0.5,
+            "reproduction_chance": 0.1,
+            "hunger_tick_chance": 0.3,
+            "ambition_tick_chance": 0.4,
+            "simulation_duration": 100,
+            "initial_population": 10
+        }"#;
+        let params: SimulationParams = serde_json::from_str(json).unwrap();
+        assert_eq!(params.random.interaction_chance, 0.5);
+        assert_eq!(params.random.reproduction_chance, 0.1);
+        assert_eq!(params.random.hunger_chance, 0.3);
+        assert_eq!(params.random.ambition_chance, 0.4);
+
+        // Les chances déjà rangées dans `random` l'emportent
+        let json = r#"{
+            "interaction_chance": 0.5,
+            "simulation_duration": 100,
+            "initial_population": 10,
+            "random": {
+                "interaction_chance": 0.9,
+                "reproduction_chance": 0.2,
+                "hunger_chance": 0.2,
+                "ambition_chance": 0.2
+            }
+        }"#;
+        let params: SimulationParams = serde_json::from_str(json).unwrap();
+        assert_eq!(params.random.interaction_chance, 0.9);
+    }
+
+    #[test]
+    fn test_parameters_round_trip() {
+        let mut params = SimulationParams::default();
+        params.random.hunger_chance = 0.7;
+        let saved = serde_json::to_string(&params).unwrap();
+        assert!(!saved.contains("hunger_tick_chance"));
+        let loaded: SimulationParams = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.random.hunger_chance, 0.7);
+    }
+}
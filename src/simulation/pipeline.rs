@@ -0,0 +1,258 @@
+// src/simulation/pipeline.rs
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+use crate::error::Result;
+use crate::models::event::Event;
+use crate::models::stats::StatLevel;
+use super::environment::Modifiers;
+use super::world::World;
+
+/// Noms des phases du tick standard, dans leur ordre d'exécution.
+pub mod phases {
+    pub const ENVIRONMENT: &str = "environment";
+    pub const AGING: &str = "aging";
+    pub const DEATHS: &str = "deaths";
+    pub const RELATIONSHIPS: &str = "relationships";
+    pub const HIERARCHY: &str = "hierarchy";
+    pub const GROUPS: &str = "groups";
+    pub const NEEDS: &str = "needs";
+    pub const DISEASES: &str = "diseases";
+    pub const CATASTROPHES: &str = "catastrophes";
+    pub const MOVEMENT: &str = "movement";
+    pub const ACTIONS: &str = "actions";
+    pub const PREGNANCIES: &str = "pregnancies";
+    pub const FOOD: &str = "food";
+    pub const FEEDING: &str = "feeding";
+    pub const SHARING: &str = "sharing";
+    pub const STATS: &str = "stats";
+
+    pub const ALL: [&str; 16] = [
+        ENVIRONMENT, AGING, DEATHS, RELATIONSHIPS, HIERARCHY, GROUPS, NEEDS, DISEASES,
+        CATASTROPHES, MOVEMENT, ACTIONS, PREGNANCIES, FOOD, FEEDING, SHARING, STATS,
+    ];
+}
+
+/// État partagé par les phases pendant un tick.
+#[derive(Debug, Default)]
+pub struct TickContext {
+    /// Événements du tick, enregistrés dans l'historique après la dernière phase
+    pub events: Vec<Event>,
+    /// Effets de l'environnement, neutres tant que la phase d'environnement n'a pas tourné
+    pub modifiers: Modifiers,
+    /// Estajoj qui ont choisi de manger pendant la phase d'actions
+    pub foragers: Vec<u32>,
+    /// Niveaux des stats en début de tick, pour signaler les seuils franchis
+    pub(crate) stat_levels: BTreeMap<u32, [StatLevel; 3]>,
+    /// Longueur de l'histoire personnelle des estajoj en début de tick, pour
+    /// dater les événements ajoutés pendant le tick
+    pub(crate) history_lengths: BTreeMap<u32, usize>,
+}
+
+/// Étape du tick. Le monde exécute ses phases actives dans l'ordre du
+/// pipeline ; une erreur interrompt le tick.
+pub trait Phase {
+    /// Nom sous lequel la phase est rangée dans le pipeline
+    fn name(&self) -> &str;
+
+    fn run(&mut self, world: &mut World, tick: &mut TickContext) -> Result<()>;
+}
+
+pub(crate) type PhaseFn = fn(&mut World, &mut TickContext);
+
+/// Phase standard, implémentée par le monde lui-même.
+pub(crate) struct Builtin {
+    name: &'static str,
+    run: PhaseFn,
+}
+
+impl Builtin {
+    pub(crate) fn new(name: &'static str, run: PhaseFn) -> Self {
+        Self { name, run }
+    }
+}
+
+impl Phase for Builtin {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn run(&mut self, world: &mut World, tick: &mut TickContext) -> Result<()> {
+        (self.run)(world, tick);
+        Ok(())
+    }
+}
+
+/// Durées d'exécution d'une phase.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PhaseTiming {
+    pub runs: u32,
+    /// Durée du dernier passage
+    pub last: Duration,
+    pub total: Duration,
+}
+
+impl PhaseTiming {
+    pub fn mean(&self) -> Duration {
+        if self.runs == 0 { Duration::ZERO } else { self.total / self.runs }
+    }
+}
+
+struct Slot {
+    phase: Box<dyn Phase>,
+    enabled: bool,
+    timing: PhaseTiming,
+}
+
+/// Phases du tick, ordonnées, chacune activable et chronométrée.
+/// Les noms sont uniques : les opérations qui en créeraient un doublon
+/// ou qui visent une phase absente retournent `false`.
+#[derive(Default)]
+pub struct Pipeline {
+    slots: Vec<Slot>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.slots.iter().position(|s| s.phase.name() == name)
+    }
+
+    fn insert(&mut self, index: usize, phase: Box<dyn Phase>) -> bool {
+        if self.position(phase.name()).is_some() {
+            return false;
+        }
+        self.slots.insert(index, Slot { phase, enabled: true, timing: PhaseTiming::default() });
+        true
+    }
+
+    /// Ajoute une phase en fin de tick.
+    pub fn push(&mut self, phase: Box<dyn Phase>) -> bool {
+        self.insert(self.slots.len(), phase)
+    }
+
+    pub fn insert_before(&mut self, anchor: &str, phase: Box<dyn Phase>) -> bool {
+        match self.position(anchor) {
+            Some(index) => self.insert(index, phase),
+            None => false,
+        }
+    }
+
+    pub fn insert_after(&mut self, anchor: &str, phase: Box<dyn Phase>) -> bool {
+        match self.position(anchor) {
+            Some(index) => self.insert(index + 1, phase),
+            None => false,
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Phase>> {
+        let index = self.position(name)?;
+        Some(self.slots.remove(index).phase)
+    }
+
+    /// Déplace une phase juste avant une autre.
+    pub fn move_before(&mut self, name: &str, anchor: &str) -> bool {
+        if name == anchor || self.position(anchor).is_none() {
+            return false;
+        }
+        let Some(phase) = self.remove(name) else { return false };
+        self.insert_before(anchor, phase)
+    }
+
+    /// Déplace une phase juste après une autre.
+    pub fn move_after(&mut self, name: &str, anchor: &str) -> bool {
+        if name == anchor || self.position(anchor).is_none() {
+            return false;
+        }
+        let Some(phase) = self.remove(name) else { return false };
+        self.insert_after(anchor, phase)
+    }
+
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        match self.position(name) {
+            Some(index) => {
+                self.slots[index].enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.position(name).is_some_and(|index| self.slots[index].enabled)
+    }
+
+    /// Noms des phases, dans l'ordre d'exécution.
+    pub fn names(&self) -> Vec<&str> {
+        self.slots.iter().map(|s| s.phase.name()).collect()
+    }
+
+    pub fn timing(&self, name: &str) -> Option<&PhaseTiming> {
+        self.position(name).map(|index| &self.slots[index].timing)
+    }
+
+    /// Durées de chaque phase, dans l'ordre d'exécution.
+    pub fn timings(&self) -> Vec<(&str, &PhaseTiming)> {
+        self.slots.iter().map(|s| (s.phase.name(), &s.timing)).collect()
+    }
+
+    /// Exécute les phases actives dans l'ordre, en les chronométrant.
+    pub(crate) fn run(&mut self, world: &mut World, tick: &mut TickContext) -> Result<()> {
+        for slot in self.slots.iter_mut().filter(|s| s.enabled) {
+            let start = Instant::now();
+            let result = slot.phase.run(world, tick);
+            let elapsed = start.elapsed();
+            slot.timing.runs += 1;
+            slot.timing.last = elapsed;
+            slot.timing.total += elapsed;
+            result?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noop(_: &mut World, _: &mut TickContext) {}
+
+    fn pipeline(names: &[&'static str]) -> Pipeline {
+        let mut pipeline = Pipeline::new();
+        for &name in names {
+            assert!(pipeline.push(Box::new(Builtin::new(name, noop))));
+        }
+        pipeline
+    }
+
+    #[test]
+    fn test_phases_can_be_reordered() {
+        let mut pipeline = pipeline(&["a", "b", "c"]);
+        assert!(!pipeline.push(Box::new(Builtin::new("b", noop))));
+        assert!(pipeline.insert_after("a", Box::new(Builtin::new("x", noop))));
+        assert!(!pipeline.insert_before("missing", Box::new(Builtin::new("y", noop))));
+        assert_eq!(pipeline.names(), vec!["a", "x", "b", "c"]);
+
+        assert!(pipeline.move_before("c", "a"));
+        assert!(pipeline.move_after("x", "b"));
+        assert!(!pipeline.move_after("a", "missing"));
+        assert_eq!(pipeline.names(), vec!["c", "a", "b", "x"]);
+
+        assert!(pipeline.remove("a").is_some());
+        assert!(pipeline.remove("a").is_none());
+        assert_eq!(pipeline.names(), vec!["c", "b", "x"]);
+    }
+
+    #[test]
+    fn test_phases_can_be_disabled() {
+        let mut pipeline = pipeline(&["a", "b"]);
+        assert!(pipeline.is_enabled("a"));
+        assert!(pipeline.set_enabled("a", false));
+        assert!(!pipeline.is_enabled("a"));
+        assert!(!pipeline.set_enabled("missing", false));
+        assert!(!pipeline.is_enabled("missing"));
+        assert_eq!(pipeline.names(), vec!["a", "b"]);
+    }
+}
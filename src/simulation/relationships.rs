@@ -0,0 +1,219 @@
+// src/simulation/relationships.rs
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::models::event::Action;
+use super::parameters::RelationshipParams;
+
+/// Ce que `from` pense de `to` : les liens sont orientés.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Relationship {
+    pub affinity: f32,  // -100..100
+    pub trust: f32,     // 0..100
+    pub grudge: f32,    // 0..100
+}
+
+impl Default for Relationship {
+    fn default() -> Self {
+        Self {
+            affinity: 0.0,
+            trust: 50.0,
+            grudge: 0.0,
+        }
+    }
+}
+
+impl Relationship {
+    fn clamp(&mut self) {
+        self.affinity = self.affinity.clamp(-100.0, 100.0);
+        self.trust = self.trust.clamp(0.0, 100.0);
+        self.grudge = self.grudge.clamp(0.0, 100.0);
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Relationships {
+    links: BTreeMap<(u32, u32), Relationship>,
+}
+
+impl Relationships {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, from: u32, to: u32) -> Relationship {
+        self.links.get(&(from, to)).cloned().unwrap_or_default()
+    }
+
+    fn entry(&mut self, from: u32, to: u32) -> &mut Relationship {
+        self.links.entry((from, to)).or_default()
+    }
+
+    fn adjust(&mut self, from: u32, to: u32, f: impl FnOnce(&mut Relationship)) {
+        let link = self.entry(from, to);
+        f(link);
+        link.clamp();
+    }
+
+    /// Met à jour les liens après une action. Un complot réussi ternit la
+    /// cible aux yeux de tous les autres ; un complot raté est découvert.
+    pub fn record_action(
+        &mut self,
+        action: &Action,
+        initiator: u32,
+        target: u32,
+        success: bool,
+        others: &[u32],
+        params: &RelationshipParams,
+    ) {
+        match action {
+            Action::Help => {
+                self.adjust(target, initiator, |r| {
+                    r.affinity += params.help_affinity;
+                    r.trust += params.help_affinity / 2.0;
+                });
+                self.adjust(initiator, target, |r| r.affinity += params.help_affinity / 5.0);
+            }
+            Action::Hurt => {
+                self.adjust(target, initiator, |r| {
+                    r.affinity -= params.hurt_affinity;
+                    r.trust -= params.hurt_affinity;
+                    r.grudge += params.hurt_grudge;
+                });
+                self.adjust(initiator, target, |r| r.affinity -= params.hurt_affinity / 5.0);
+            }
+            Action::Plot if success => {
+                for &other in others.iter().filter(|&&id| id != initiator && id != target) {
+                    self.adjust(other, target, |r| {
+                        r.affinity -= params.plot_smear;
+                        r.trust -= params.plot_smear;
+                    });
+                }
+                self.adjust(initiator, target, |r| r.trust -= params.plot_smear);
+            }
+            Action::Plot => {
+                self.adjust(target, initiator, |r| {
+                    r.trust -= params.plot_grudge;
+                    r.grudge += params.plot_grudge;
+                });
+            }
+        }
+    }
+
+    /// Le perdant d'un défi en garde rancune au vainqueur.
+    pub fn record_challenge(&mut self, challenger: u32, rival: u32, won: bool, params: &RelationshipParams) {
+        let (winner, loser) = if won { (challenger, rival) } else { (rival, challenger) };
+        self.adjust(loser, winner, |r| {
+            r.affinity -= params.challenge_grudge / 2.0;
+            r.grudge += params.challenge_grudge;
+        });
+    }
+
+    /// Les voisins voient d'un mauvais œil celui qui accapare la nourriture.
+    pub fn record_hoard(&mut self, hoarder: u32, witnesses: &[u32], params: &RelationshipParams) {
+        for &other in witnesses.iter().filter(|&&id| id != hoarder) {
+            self.adjust(other, hoarder, |r| r.affinity -= params.hoard_resentment);
+        }
+    }
+
+    /// Les sentiments s'estompent avec le temps.
+    pub fn decay(&mut self, rate: f32) {
+        for link in self.links.values_mut() {
+            link.affinity *= 1.0 - rate;
+            link.grudge *= 1.0 - rate;
+            link.trust += (50.0 - link.trust) * rate;
+        }
+    }
+
+    /// Oublie tous les liens impliquant un estajo disparu.
+    pub fn forget(&mut self, id: u32) {
+        self.links.retain(|&(from, to), _| from != id && to != id);
+    }
+
+    /// Somme des affinités positives que les autres portent à `id`.
+    pub fn popularity(&self, id: u32) -> f32 {
+        self.links.iter()
+            .filter(|(&(_, to), _)| to == id)
+            .map(|(_, r)| r.affinity.max(0.0))
+            .sum()
+    }
+
+    /// Popularité de chaque estajo aimé par au moins un autre, en un seul
+    /// passage sur les liens.
+    pub fn popularities(&self) -> BTreeMap<u32, f32> {
+        let mut popularity = BTreeMap::new();
+        for (&(_, to), r) in &self.links {
+            *popularity.entry(to).or_insert(0.0) += r.affinity.max(0.0);
+        }
+        popularity
+    }
+
+    /// Paires `(de, vers)` entre lesquelles un lien existe.
+    pub fn pairs(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.links.keys().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+}
+
+/// Poids de tirage dérivé d'un sentiment : 1 pour l'indifférence,
+/// croissant avec la valeur selon `bias`.
+pub fn weight(value: f32, bias: f32) -> f64 {
+    1.0 + (value.max(0.0) * bias) as f64 / 20.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_help_builds_affinity() {
+        let mut rels = Relationships::new();
+        rels.record_action(&Action::Help, 1, 2, true, &[1, 2], &RelationshipParams::default());
+        assert!(rels.get(2, 1).affinity > 0.0);
+        assert!(rels.get(2, 1).trust > 50.0);
+    }
+
+    #[test]
+    fn test_hurt_creates_grudge() {
+        let mut rels = Relationships::new();
+        rels.record_action(&Action::Hurt, 1, 2, true, &[1, 2], &RelationshipParams::default());
+        assert!(rels.get(2, 1).grudge > 0.0);
+        assert!(rels.get(2, 1).affinity < 0.0);
+    }
+
+    #[test]
+    fn test_successful_plot_smears_target() {
+        let mut rels = Relationships::new();
+        rels.record_action(&Action::Plot, 1, 2, true, &[1, 2, 3], &RelationshipParams::default());
+        assert!(rels.get(3, 2).affinity < 0.0);
+        assert_eq!(rels.get(2, 1).grudge, 0.0);
+    }
+
+    #[test]
+    fn test_popularities_match_popularity() {
+        let mut rels = Relationships::new();
+        let params = RelationshipParams::default();
+        rels.record_action(&Action::Help, 1, 2, true, &[1, 2, 3], &params);
+        rels.record_action(&Action::Help, 3, 2, true, &[1, 2, 3], &params);
+        rels.record_action(&Action::Hurt, 2, 3, true, &[1, 2, 3], &params);
+        let popularity = rels.popularities();
+        for id in 1..=3 {
+            assert_eq!(popularity.get(&id).copied().unwrap_or(0.0), rels.popularity(id));
+        }
+        assert!(popularity[&1] > 0.0);
+    }
+
+    #[test]
+    fn test_forget() {
+        let mut rels = Relationships::new();
+        rels.record_action(&Action::Help, 1, 2, true, &[1, 2], &RelationshipParams::default());
+        rels.forget(2);
+        assert!(rels.is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+// src/simulation/space.rs
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::models::position::Position;
+
+/// Grille rectangulaire sur laquelle vivent les estajoj.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Grid {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Grid {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width: width.max(1),
+            height: height.max(1),
+        }
+    }
+
+    pub fn cells(&self) -> usize {
+        (self.width * self.height) as usize
+    }
+
+    pub fn index(&self, pos: Position) -> usize {
+        (pos.y * self.width + pos.x) as usize
+    }
+
+    pub fn position(&self, index: usize) -> Position {
+        let index = index as u32;
+        Position::new(index % self.width, index / self.width)
+    }
+
+    pub fn random_position(&self, rng: &mut impl Rng) -> Position {
+        Position::new(rng.gen_range(0..self.width), rng.gen_range(0..self.height))
+    }
+
+    /// Déplacement borné par les limites de la grille.
+    pub fn step(&self, from: Position, dx: i32, dy: i32) -> Position {
+        let x = (from.x as i64 + dx as i64).clamp(0, self.width as i64 - 1);
+        let y = (from.y as i64 + dy as i64).clamp(0, self.height as i64 - 1);
+        Position::new(x as u32, y as u32)
+    }
+
+    /// Avance d'au plus `speed` cases vers `target`.
+    pub fn step_towards(&self, from: Position, target: Position, speed: u32) -> Position {
+        let toward = |a: u32, b: u32| (b as i64 - a as i64).clamp(-(speed as i64), speed as i64) as i32;
+        self.step(from, toward(from.x, target.x), toward(from.y, target.y))
+    }
+
+    /// Cases situées à au plus `radius` de `center`.
+    pub fn within(&self, center: Position, radius: f32) -> Vec<Position> {
+        let reach = radius.max(0.0).floor() as i64;
+        let mut cells = Vec::new();
+        for dy in -reach..=reach {
+            for dx in -reach..=reach {
+                let x = center.x as i64 + dx;
+                let y = center.y as i64 + dy;
+                if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+                    continue;
+                }
+                let pos = Position::new(x as u32, y as u32);
+                if center.distance(&pos) <= radius {
+                    cells.push(pos);
+                }
+            }
+        }
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_is_clamped() {
+        let grid = Grid::new(5, 5);
+        assert_eq!(grid.step(Position::new(0, 4), -1, 1), Position::new(0, 4));
+        assert_eq!(grid.step(Position::new(2, 2), 1, -1), Position::new(3, 1));
+    }
+
+    #[test]
+    fn test_step_towards() {
+        let grid = Grid::new(10, 10);
+        assert_eq!(grid.step_towards(Position::new(0, 0), Position::new(5, 1), 2), Position::new(2, 1));
+    }
+
+    #[test]
+    fn test_within_radius() {
+        let grid = Grid::new(10, 10);
+        assert_eq!(grid.within(Position::new(5, 5), 1.0).len(), 5);
+        assert_eq!(grid.within(Position::new(0, 0), 1.0).len(), 3);
+    }
+
+    #[test]
+    fn test_index_roundtrip() {
+        let grid = Grid::new(7, 3);
+        let pos = Position::new(4, 2);
+        assert_eq!(grid.position(grid.index(pos)), pos);
+    }
+}
@@ -0,0 +1,138 @@
+// src/simulation/stats.rs
+use crate::models::estajo::Estajo;
+use crate::models::genetics::MIN_DIVISOR;
+use crate::models::event::{Action, Event, EventType};
+use crate::models::stats::{Stat, StatLevel};
+use crate::models::traits::hooks;
+use super::parameters::StatsParams;
+
+/// Les gains sont amplifiés par le facteur génétique, les pertes atténuées.
+fn scaled(delta: f32, factor: f32) -> f32 {
+    if delta > 0.0 { delta * factor } else { delta / factor.max(MIN_DIVISOR) }
+}
+
+fn hook(stat: Stat) -> &'static str {
+    match stat {
+        Stat::Energy => hooks::ENERGY_GAIN,
+        Stat::Satisfaction => hooks::SATISFACTION_GAIN,
+        Stat::Influence => hooks::INFLUENCE_GAIN,
+    }
+}
+
+fn adjust(estajo: &mut Estajo, stat: Stat, delta: f32) {
+    let factor = estajo.genetics.factor(hook(stat));
+    estajo.stats.adjust(stat, scaled(delta, factor));
+}
+
+pub fn levels(estajo: &Estajo, params: &StatsParams) -> [StatLevel; 3] {
+    Stat::ALL.map(|stat| estajo.stats.level(stat, params.low_threshold, params.high_threshold))
+}
+
+/// Évolution naturelle à chaque tick : l'énergie suit la nourriture, la
+/// satisfaction suit les besoins comblés, l'influence s'érode.
+pub fn drift(estajo: &mut Estajo, params: &StatsParams) {
+    let hunger = (estajo.needs.hunger - 50.0) / 50.0;
+    let ambition = (estajo.needs.ambition - 50.0) / 50.0;
+    adjust(estajo, Stat::Energy, params.energy_recovery * hunger);
+    adjust(estajo, Stat::Satisfaction, params.satisfaction_rate * (hunger - ambition));
+    let decay = -estajo.stats.influence * params.influence_decay;
+    estajo.stats.adjust(Stat::Influence, decay);
+}
+
+pub fn rest(estajo: &mut Estajo, params: &StatsParams) {
+    adjust(estajo, Stat::Energy, params.rest_energy);
+}
+
+pub fn after_action(
+    action: &Action,
+    success: bool,
+    initiator: &mut Estajo,
+    target: &mut Estajo,
+    params: &StatsParams,
+) {
+    adjust(initiator, Stat::Energy, -params.action_energy_cost);
+    match action {
+        Action::Help => {
+            adjust(initiator, Stat::Influence, params.help_influence);
+            adjust(target, Stat::Satisfaction, params.help_satisfaction);
+        }
+        Action::Hurt => {
+            adjust(target, Stat::Satisfaction, -params.hurt_satisfaction);
+        }
+        Action::Plot if success => {
+            adjust(initiator, Stat::Influence, params.plot_influence);
+            adjust(target, Stat::Influence, -params.plot_influence);
+        }
+        Action::Plot => {
+            adjust(initiator, Stat::Influence, -params.plot_influence / 2.0);
+        }
+    }
+}
+
+/// Événements pour chaque seuil franchi depuis `before`, inscrits aussi
+/// dans l'historique de l'estajo.
+pub fn crossings(before: [StatLevel; 3], estajo: &mut Estajo, params: &StatsParams) -> Vec<Event> {
+    let after = levels(estajo, params);
+    let mut events = Vec::new();
+    for (i, stat) in Stat::ALL.iter().enumerate() {
+        if before[i] == after[i] {
+            continue;
+        }
+        let value = estajo.stats.get(*stat);
+        let event_type = EventType::StateChange(stat.state_change());
+        estajo.add_event(Event::new(
+            event_type.clone(),
+            format!("{} {} ({:.1})", stat, after[i], value)
+        ));
+        events.push(Event::new(
+            event_type,
+            format!("{} {} {} ({:.1})", estajo.name, stat, after[i], value)
+        ));
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::models::event::StateChange;
+    use crate::test_support;
+
+    fn estajo(id: u32) -> Estajo {
+        test_support::estajo(id, &mut StdRng::seed_from_u64(id as u64))
+    }
+
+    #[test]
+    fn test_hunger_drains_energy() {
+        let params = StatsParams::default();
+        let mut e = estajo(0);
+        e.needs.hunger = 0.0;
+        drift(&mut e, &params);
+        assert!(e.stats.energy < 100.0);
+    }
+
+    #[test]
+    fn test_successful_plot_moves_influence() {
+        let params = StatsParams::default();
+        let (mut a, mut b) = (estajo(0), estajo(1));
+        after_action(&Action::Plot, true, &mut a, &mut b, &params);
+        assert!(a.stats.influence > 10.0);
+        assert!(b.stats.influence < 10.0);
+    }
+
+    #[test]
+    fn test_crossing_emits_event() {
+        let params = StatsParams::default();
+        let mut e = estajo(0);
+        let before = levels(&e, &params);
+        assert!(crossings(before, &mut e, &params).is_empty());
+
+        e.stats.energy = 10.0;
+        let events = crossings(before, &mut e, &params);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0].event_type, EventType::StateChange(StateChange::EnergyUpdate)));
+        assert_eq!(e.history.len(), 1);
+    }
+}
@@ -0,0 +1,144 @@
+// src/simulation/termination.rs
+use std::collections::BTreeMap;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::models::estajo::{Estajo, Sex};
+use super::outcome::EndCondition;
+
+/// Condition d'arrêt d'une simulation. Elles se combinent : la simulation
+/// s'arrête dès que l'une d'elles est remplie.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StopCondition {
+    /// Plus aucun estajo vivant
+    Extinction,
+    /// `simulation_duration` ticks écoulés
+    MaxTicks,
+    /// Population vivante d'au moins `population` estajoj
+    PopulationCap { population: usize },
+    /// Il ne reste que des mâles ou que des femelles
+    SingleSex,
+    /// Les allèles d'un trait (ou de n'importe lequel, sans nom) ne
+    /// diffèrent plus de `tolerance` dans toute la population
+    TraitFixation {
+        #[serde(default)]
+        name: Option<String>,
+        tolerance: f32,
+    },
+    /// Temps de calcul écoulé depuis la création du monde
+    TimeBudget { seconds: f64 },
+}
+
+/// Ce que les conditions d'arrêt examinent.
+pub struct Census<'a> {
+    pub estajoj: &'a BTreeMap<u32, Estajo>,
+    pub tick: u32,
+    pub duration: u32,
+    pub elapsed: Duration,
+}
+
+impl StopCondition {
+    /// Condition qui finit toujours par être remplie, quelle que soit
+    /// l'évolution de la population.
+    pub fn is_bounded(&self) -> bool {
+        match self {
+            StopCondition::MaxTicks => true,
+            StopCondition::TimeBudget { seconds } => seconds.is_finite(),
+            _ => false,
+        }
+    }
+
+    pub fn check(&self, census: &Census) -> Option<EndCondition> {
+        let mut living = census.estajoj.values().filter(|e| e.is_alive()).peekable();
+        match self {
+            StopCondition::Extinction => living.peek().is_none().then_some(EndCondition::Extinction),
+            StopCondition::MaxTicks => (census.tick >= census.duration).then_some(EndCondition::MaxTicks),
+            StopCondition::PopulationCap { population } => {
+                (living.count() >= *population).then_some(EndCondition::PopulationCap)
+            }
+            StopCondition::SingleSex => {
+                let (males, females): (Vec<&Estajo>, Vec<&Estajo>) = living.partition(|e| e.sex == Sex::Male);
+                (males.is_empty() != females.is_empty()).then_some(EndCondition::SingleSex)
+            }
+            StopCondition::TraitFixation { name, tolerance } => {
+                let living: Vec<&Estajo> = living.collect();
+                let first = living.first()?;
+                first.genetics.genome.iter()
+                    .map(|locus| &locus.name)
+                    .filter(|locus| name.as_ref().is_none_or(|name| name == *locus))
+                    .find(|locus| {
+                        let alleles: Vec<f32> = living.iter()
+                            .flat_map(|e| e.genetics.genome.iter().filter(|l| &l.name == *locus))
+                            .flat_map(|l| l.alleles)
+                            .collect();
+                        let min = alleles.iter().copied().fold(f32::INFINITY, f32::min);
+                        let max = alleles.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                        max - min <= *tolerance
+                    })
+                    .map(|locus| EndCondition::TraitFixation(locus.clone()))
+            }
+            StopCondition::TimeBudget { seconds } => {
+                (census.elapsed.as_secs_f64() >= *seconds).then_some(EndCondition::TimeBudget)
+            }
+        }
+    }
+}
+
+/// Première condition remplie, dans l'ordre de la liste.
+pub fn check_all(conditions: &[StopCondition], census: &Census) -> Option<EndCondition> {
+    conditions.iter().find_map(|condition| condition.check(census))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::traits::ENERGY;
+    use crate::test_support;
+
+    fn population(sexes: &[Sex]) -> BTreeMap<u32, Estajo> {
+        test_support::population(sexes, |e, sex| {
+            e.sex = if *sex == Sex::Male { Sex::Male } else { Sex::Female };
+        })
+    }
+
+    fn census(estajoj: &BTreeMap<u32, Estajo>, tick: u32) -> Census<'_> {
+        Census { estajoj, tick, duration: 100, elapsed: Duration::from_secs(1) }
+    }
+
+    #[test]
+    fn test_population_conditions() {
+        let couple = population(&[Sex::Male, Sex::Female]);
+        let males = population(&[Sex::Male, Sex::Male]);
+        let nobody = BTreeMap::new();
+        let conditions = [
+            StopCondition::Extinction,
+            StopCondition::SingleSex,
+            StopCondition::PopulationCap { population: 3 },
+            StopCondition::MaxTicks,
+        ];
+        assert_eq!(check_all(&conditions, &census(&couple, 10)), None);
+        assert_eq!(check_all(&conditions, &census(&couple, 100)), Some(EndCondition::MaxTicks));
+        assert_eq!(check_all(&conditions, &census(&males, 10)), Some(EndCondition::SingleSex));
+        assert_eq!(check_all(&conditions, &census(&nobody, 10)), Some(EndCondition::Extinction));
+        let crowd = population(&[Sex::Male, Sex::Female, Sex::Female]);
+        assert_eq!(check_all(&conditions, &census(&crowd, 10)), Some(EndCondition::PopulationCap));
+        let budget = StopCondition::TimeBudget { seconds: 0.5 };
+        assert_eq!(budget.check(&census(&crowd, 10)), Some(EndCondition::TimeBudget));
+        assert!(budget.is_bounded());
+        assert!(!StopCondition::TimeBudget { seconds: f64::INFINITY }.is_bounded());
+        assert!(!StopCondition::Extinction.is_bounded());
+    }
+
+    #[test]
+    fn test_trait_fixation() {
+        let mut estajoj = population(&[Sex::Male, Sex::Female]);
+        let fixation = StopCondition::TraitFixation { name: Some(ENERGY.to_string()), tolerance: 0.01 };
+        assert_eq!(fixation.check(&census(&estajoj, 10)), None);
+        for estajo in estajoj.values_mut() {
+            for locus in estajo.genetics.genome.iter_mut().filter(|l| l.name == ENERGY) {
+                locus.alleles = [1.0, 1.0];
+            }
+        }
+        assert_eq!(fixation.check(&census(&estajoj, 10)), Some(EndCondition::TraitFixation(ENERGY.to_string())));
+    }
+}
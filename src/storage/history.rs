@@ -2,13 +2,17 @@
 
 
 // src/storage/history.rs
+use std::collections::BTreeMap;
 use std::fs::{File, OpenOptions};
 use std::io::BufWriter;
-use std::io::Write;
+use std::io::{Seek, SeekFrom, Write};
+use std::path::Path;
 use chrono::{DateTime, Local};
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 use crate::models::event::Event;
+use crate::simulation::disease::InfectionCounts;
+use crate::simulation::outcome::EndCondition;
 use crate::simulation::parameters::SimulationParams;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,6 +21,14 @@ pub struct SimulationRecord {
     start_time: DateTime<Local>,
     parameters: SimulationParams,
     events: Vec<Event>,
+    /// Bilan des maladies à chaque tick, par maladie
+    #[serde(default)]
+    epidemic: Vec<BTreeMap<String, InfectionCounts>>,
+    /// Tick et raison de la fin de la simulation, une fois atteinte
+    #[serde(default)]
+    end_tick: Option<u32>,
+    #[serde(default)]
+    end_condition: Option<EndCondition>,
 }
 
 pub struct HistoryStorage {
@@ -26,17 +38,22 @@ pub struct HistoryStorage {
 
 impl HistoryStorage {
     pub fn new(parameters: SimulationParams) -> std::io::Result<Self> {
-        let simulation_id = Uuid::new_v4().to_string();
-        let start_time = Local::now();
         let filename = format!(
             "simulation_{}.json",
-            start_time.format("%Y%m%d_%H%M%S")
+            Local::now().format("%Y%m%d_%H%M%S")
         );
+        Self::create(filename, parameters)
+    }
 
+    /// Enregistre la simulation dans le fichier donné.
+    pub fn create(path: impl AsRef<Path>, parameters: SimulationParams) -> std::io::Result<Self> {
+        let simulation_id = Uuid::new_v4().to_string();
+        let start_time = Local::now();
         let file = OpenOptions::new()
             .write(true)
             .create(true)
-            .open(filename)?;
+            .truncate(true)
+            .open(path)?;
 
         Ok(Self {
             current_simulation: SimulationRecord {
@@ -44,6 +61,9 @@ impl HistoryStorage {
                 start_time,
                 parameters,
                 events: Vec::new(),
+                epidemic: Vec::new(),
+                end_tick: None,
+                end_condition: None,
             },
             file_writer: BufWriter::new(file),
         })
@@ -54,7 +74,27 @@ impl HistoryStorage {
         Ok(())
     }
 
+    pub fn record_epidemic(&mut self, counts: BTreeMap<String, InfectionCounts>) {
+        self.current_simulation.epidemic.push(counts);
+    }
+
+    /// Bilans des maladies, du premier au dernier tick.
+    pub fn epidemic(&self) -> &[BTreeMap<String, InfectionCounts>] {
+        &self.current_simulation.epidemic
+    }
+
+    pub fn record_end(&mut self, tick: u32, condition: EndCondition) {
+        self.current_simulation.end_tick = Some(tick);
+        self.current_simulation.end_condition = Some(condition);
+    }
+
+    /// Réécrit le fichier avec l'état courant de l'enregistrement, qui reste
+    /// ainsi un document JSON unique.
     pub fn save(&mut self) -> std::io::Result<()> {
+        self.file_writer.flush()?;
+        let file = self.file_writer.get_mut();
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
         serde_json::to_writer_pretty(&mut self.file_writer, &self.current_simulation)?;
         self.file_writer.flush()?;
         Ok(())
@@ -91,4 +131,20 @@ mod tests {
         );
         assert!(storage.record_event(event).is_ok());
     }
+
+    #[test]
+    fn test_saved_record_is_a_single_document() {
+        let path = std::env::temp_dir().join(format!("estajoj_{}.json", Uuid::new_v4()));
+        let mut storage = HistoryStorage::create(&path, SimulationParams::default()).unwrap();
+        storage.save().unwrap();
+        storage.record_event(Event::new(EventType::Need(Need::Food), "Test event".to_string())).unwrap();
+        storage.record_end(3, EndCondition::MaxTicks);
+        storage.save().unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let record: SimulationRecord = serde_json::from_str(&saved).unwrap();
+        assert_eq!(record.events.len(), 1);
+        assert_eq!(record.end_tick, Some(3));
+    }
 }
\ No newline at end of file
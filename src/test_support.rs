@@ -0,0 +1,29 @@
+// src/test_support.rs
+//! Fabriques d'estajoj partagées par les tests des différents modules.
+use std::collections::BTreeMap;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use crate::models::Estajo;
+
+/// Estajo nommé d'après son identifiant, comme ceux que crée le monde.
+pub fn estajo(id: u32, rng: &mut impl Rng) -> Estajo {
+    Estajo::new(id, format!("Estajo_{}", id), rng)
+}
+
+/// Deux estajoj d'identifiants 0 et 1.
+pub fn pair(rng: &mut impl Rng) -> (Estajo, Estajo) {
+    (estajo(0, rng), estajo(1, rng))
+}
+
+/// Un estajo par valeur, d'identifiants 0, 1, 2…, ajusté par `setup`.
+pub fn population<T>(values: &[T], setup: impl Fn(&mut Estajo, &T)) -> BTreeMap<u32, Estajo> {
+    let mut rng = StdRng::seed_from_u64(1);
+    values.iter()
+        .enumerate()
+        .map(|(id, value)| {
+            let mut e = estajo(id as u32, &mut rng);
+            setup(&mut e, value);
+            (id as u32, e)
+        })
+        .collect()
+}
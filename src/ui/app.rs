@@ -1,6 +1,7 @@
 // src/ui/app.rs
 use std::error::Error;
 use crossterm::event::{self, Event, KeyCode};
+use crate::error::Result;
 use crate::simulation::world::World;
 
 pub struct App {
@@ -20,19 +21,15 @@ impl App {
        }
    }
 
-   pub fn tick(&mut self) -> Result<(), Box<dyn Error>> {
-    if !self.is_paused {
-        if let Err(e) = self.world.tick() {
-            if e.kind() == std::io::ErrorKind::Other && e.to_string() == "All estajoj are dead!" {
-                return Err(Box::new(e));
-            }
-            return Err(Box::new(e));
-        }
-    }
-    Ok(())
-}
+   /// Fait avancer la simulation, sauf en pause ou une fois terminée.
+   pub fn tick(&mut self) -> Result<()> {
+       if !self.is_paused && self.world.end().is_none() {
+           self.world.tick()?;
+       }
+       Ok(())
+   }
 
-   pub fn handle_input(&mut self) -> Result<(), Box<dyn Error>> {
+   pub fn handle_input(&mut self) -> std::result::Result<(), Box<dyn Error>> {
        if event::poll(std::time::Duration::from_millis(100))? {
            if let Event::Key(key) = event::read()? {
                match key.code {
@@ -11,6 +11,26 @@ use ratatui::{
  use crate::models::estajo::Sex;
  use super::app::App;
  
+ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
+    let state = match app.world.end() {
+        Some(end) => format!("  |  ENDED: {}", end),
+        None if app.is_paused => "  |  PAUSED".to_string(),
+        None => String::new(),
+    };
+    let text = format!(
+        "Tick {}/{}  |  {}  |  Seed {}{}",
+        app.world.current_tick(),
+        app.world.params().simulation_duration,
+        app.world.environment(),
+        app.world.seed(),
+        state
+    );
+    let paragraph = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(paragraph, area);
+ }
+ 
  fn draw_population(f: &mut Frame, app: &App, area: Rect) {
     let total = app.world.estajoj.len();
     let males = app.world.estajoj.values()
@@ -22,6 +42,25 @@ use ratatui::{
         Line::from(vec![Span::raw(format!("Total: {}", total))]),
         Line::from(vec![Span::raw(format!("Males: {}", males))]),
         Line::from(vec![Span::raw(format!("Females: {}", females))]),
+        Line::from(vec![Span::raw(format!(
+            "Groups: {}",
+            app.world.groups.iter()
+                .map(|(id, members)| format!("Group_{} ({})", id, members.len()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))]),
+        Line::from(vec![Span::raw(format!(
+            "Diseases: {}",
+            app.world.epidemic().last()
+                .map(|tick| tick.iter()
+                    .map(|(name, c)| format!(
+                        "{} {} sick, {} incubating, {} immune",
+                        name, c.sick, c.incubating, c.immune
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("; "))
+                .unwrap_or_default()
+        ))]),
     ]);
  
     let block = Block::default()
@@ -60,6 +99,10 @@ use ratatui::{
     let text = Text::from(vec![
         Line::from(vec![Span::raw(format!("Hungry: {}", hungry_count))]),
         Line::from(vec![Span::raw(format!("Ambitious: {}", ambitious_count))]),
+        Line::from(vec![Span::raw(format!(
+            "Food stock: {:.0}/{:.0}",
+            app.world.food.total(), app.world.food.capacity()
+        ))]),
     ]);
  
     let block = Block::default()
@@ -71,15 +114,90 @@ use ratatui::{
     f.render_widget(paragraph, area);
  }
  
+ fn draw_hierarchy(f: &mut Frame, app: &App, area: Rect) {
+    let hierarchy = &app.world.hierarchy;
+    let leaders = hierarchy.leaders();
+    let mut lines: Vec<ListItem> = leaders.iter()
+        .map(|&id| ListItem::new(format!(
+            "Estajo_{}  status {:.0}  followers {}",
+            id, hierarchy.status(id), hierarchy.followers(id).len()
+        )))
+        .collect();
+    if lines.is_empty() {
+        lines.push(ListItem::new("No leader"));
+    }
+    let top: Vec<String> = hierarchy.ranking().iter()
+        .filter(|(id, _)| !leaders.contains(id))
+        .take(5)
+        .map(|(id, status)| format!("Estajo_{} ({:.0})", id, status))
+        .collect();
+    if !top.is_empty() {
+        lines.push(ListItem::new(format!("Next: {}", top.join(", "))));
+    }
+
+    let block = Block::default()
+        .title("Hierarchy")
+        .borders(Borders::ALL);
+    let list = List::new(lines)
+        .block(block)
+        .style(Style::default().fg(Color::White));
+    f.render_widget(list, area);
+ }
+ 
  fn draw_selected_estajo(f: &mut Frame, app: &App, area: Rect) {
     let text = if let Some(id) = app.selected_estajo_id {
         if let Some(estajo) = app.world.estajoj.get(&id) {
             Text::from(vec![
                 Line::from(vec![Span::raw(format!("Name: {}", estajo.name))]),
                 Line::from(vec![Span::raw(format!("Sex: {:?}", estajo.sex))]),
+                Line::from(vec![Span::raw(format!(
+                    "Age: {} ({})",
+                    estajo.age, app.world.life_stage(estajo)
+                ))]),
+                Line::from(vec![Span::raw(format!(
+                    "Generation: {}  Children: {}",
+                    estajo.generation, app.world.lineage.children(id).len()
+                ))]),
                 Line::from(vec![Span::raw(format!("Life: {:.1}%", estajo.life))]),
+                Line::from(vec![Span::raw(format!(
+                    "Health: {}",
+                    estajo.infection.as_ref()
+                        .map(|infection| format!("{} (day {})", infection.disease, infection.elapsed))
+                        .unwrap_or_else(|| "healthy".to_string())
+                ))]),
+                Line::from(vec![Span::raw(format!(
+                    "Position: ({}, {})",
+                    estajo.position.x, estajo.position.y
+                ))]),
                 Line::from(vec![Span::raw(format!("Hunger: {:.1}%", estajo.needs.hunger))]),
                 Line::from(vec![Span::raw(format!("Ambition: {:.1}%", estajo.needs.ambition))]),
+                Line::from(vec![Span::raw(format!("Mating drive: {:.1}%", estajo.needs.reproduction))]),
+                Line::from(vec![Span::raw(format!("Hoard: {:.1}", estajo.hoard))]),
+                Line::from(vec![Span::raw(format!("Behavior: {}", app.world.behavior_of(estajo.id)))]),
+                Line::from(vec![Span::raw(format!(
+                    "Group: {}",
+                    app.world.groups.group_of(id)
+                        .map(|group| format!("Group_{}", group))
+                        .unwrap_or_else(|| "none".to_string())
+                ))]),
+                Line::from(vec![Span::raw(format!(
+                    "Status: {:.0} (rank {})  Leader: {}",
+                    app.world.hierarchy.status(id),
+                    app.world.hierarchy.rank(id).unwrap_or(0),
+                    app.world.hierarchy.leader_of(id)
+                        .map(|leader| format!("Estajo_{}", leader))
+                        .unwrap_or_else(|| "none".to_string())
+                ))]),
+                Line::from(vec![Span::raw(format!(
+                    "Energy: {:.0}  Satisfaction: {:.0}  Influence: {:.0}",
+                    estajo.stats.energy, estajo.stats.satisfaction, estajo.stats.influence
+                ))]),
+                Line::from(vec![Span::raw(
+                    estajo.genetics.traits.iter()
+                        .map(|(name, value)| format!("{}: {:.2}", name, value))
+                        .collect::<Vec<_>>()
+                        .join("  ")
+                )]),
             ])
         } else {
             Text::from(vec![Line::from(vec![Span::raw("No estajo selected")])])
@@ -102,6 +220,7 @@ use ratatui::{
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
+            Constraint::Length(3),
             Constraint::Percentage(50),
             Constraint::Percentage(50),
         ].as_ref())
@@ -113,7 +232,7 @@ use ratatui::{
             Constraint::Percentage(50),
             Constraint::Percentage(50),
         ].as_ref())
-        .split(chunks[0]);
+        .split(chunks[1]);
  
     let bottom_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -121,10 +240,20 @@ use ratatui::{
             Constraint::Percentage(50),
             Constraint::Percentage(50),
         ].as_ref())
-        .split(chunks[1]);
+        .split(chunks[2]);
  
+    draw_header(f, app, chunks[0]);
     draw_population(f, app, top_chunks[0]);
     draw_events_log(f, app, top_chunks[1]);
-    draw_needs_status(f, app, bottom_chunks[0]);
+    let status_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(40),
+            Constraint::Percentage(60),
+        ].as_ref())
+        .split(bottom_chunks[0]);
+ 
+    draw_needs_status(f, app, status_chunks[0]);
+    draw_hierarchy(f, app, status_chunks[1]);
     draw_selected_estajo(f, app, bottom_chunks[1]);
  }
\ No newline at end of file